@@ -0,0 +1,268 @@
+//! Ledger of known answers, stored next to the input files as `{year}-{day}.answers`
+//!
+//! Each line records one answer as `<part> <verdict> <answer>`, where the verdict is one of
+//! `correct`, `incorrect`, `too-high` or `too-low`.
+
+use std::{fmt::Write as _, io, path::PathBuf};
+
+use crate::{
+    get::GetError,
+    solution::Part,
+    submit::{Hint, Verdict},
+};
+
+#[derive(Debug, Default, Clone)]
+pub struct Answers {
+    a: PartAnswers,
+    b: PartAnswers,
+}
+
+#[derive(Debug, Default, Clone)]
+struct PartAnswers {
+    correct: Option<String>,
+    incorrect: Vec<(String, Option<Hint>)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Check {
+    Correct,
+    Incorrect(Option<Hint>),
+    Unknown,
+}
+
+impl Answers {
+    pub fn load(year: u32, day: u32) -> Result<Self, GetError> {
+        let path = answers_file_path(year, day)?;
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(error) => return Err(error.into()),
+        };
+
+        let mut answers = Self::default();
+        for (index, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let (part, verdict, answer) = parse_line(line).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid answer ledger line {}: {line:?}", index + 1),
+                )
+            })?;
+            answers.record(part, answer, verdict);
+        }
+
+        Ok(answers)
+    }
+
+    pub fn save(&self, year: u32, day: u32) -> Result<(), GetError> {
+        let path = answers_file_path(year, day)?;
+
+        let mut contents = String::new();
+        for part in Part::ALL {
+            let answers = self.get(part);
+            if let Some(answer) = answers.correct.as_deref() {
+                let _ = writeln!(contents, "{part} correct {answer}");
+            }
+
+            for (answer, hint) in answers.incorrect.iter() {
+                let verdict = match hint {
+                    None => "incorrect",
+                    Some(Hint::TooHigh) => "too-high",
+                    Some(Hint::TooLow) => "too-low",
+                };
+                let _ = writeln!(contents, "{part} {verdict} {answer}");
+            }
+        }
+
+        std::fs::write(path, contents)?;
+        Ok(())
+    }
+
+    /// Records a server verdict; returns `false` if the verdict carries no information
+    pub fn record(&mut self, part: Part, answer: &str, verdict: Verdict) -> bool {
+        let answers = self.get_mut(part);
+        match verdict {
+            Verdict::Correct => {
+                answers.correct = Some(answer.to_string());
+                true
+            }
+            Verdict::Incorrect(hint) => {
+                if answers.incorrect.iter().all(|(a, _)| a != answer) {
+                    answers.incorrect.push((answer.to_string(), hint));
+                }
+                true
+            }
+            Verdict::RateLimited(_) | Verdict::AlreadySolved => false,
+        }
+    }
+
+    pub fn check(&self, part: Part, answer: &str) -> Check {
+        let answers = self.get(part);
+        if let Some((_, hint)) = answers.incorrect.iter().find(|(a, _)| a == answer) {
+            return Check::Incorrect(*hint);
+        }
+
+        match answers.correct.as_deref() {
+            Some(correct) if correct == answer => Check::Correct,
+            Some(_) => Check::Incorrect(None),
+            None => Check::Unknown,
+        }
+    }
+
+    pub fn is_solved(&self, part: Part) -> bool {
+        self.get(part).correct.is_some()
+    }
+
+    /// Checks a candidate answer against the ledger before submitting it
+    pub fn guard(&self, part: Part, answer: &str) -> Result<(), Refusal> {
+        let answers = self.get(part);
+        match answers.correct.as_deref() {
+            Some(correct) if correct == answer => return Err(Refusal::AlreadyCorrect),
+            Some(correct) => return Err(Refusal::DiffersFromCorrect(correct.to_string())),
+            None => {}
+        }
+
+        if let Some((_, hint)) = answers.incorrect.iter().find(|(a, _)| a == answer) {
+            return Err(Refusal::KnownIncorrect(*hint));
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+
+        if let Some(upper) = answers.bound(Hint::TooHigh)
+            && value >= upper
+        {
+            return Err(Refusal::TooHigh(upper));
+        }
+
+        if let Some(lower) = answers.bound(Hint::TooLow)
+            && value <= lower
+        {
+            return Err(Refusal::TooLow(lower));
+        }
+
+        Ok(())
+    }
+
+    fn get(&self, part: Part) -> &PartAnswers {
+        match part {
+            Part::A => &self.a,
+            Part::B => &self.b,
+        }
+    }
+
+    fn get_mut(&mut self, part: Part) -> &mut PartAnswers {
+        match part {
+            Part::A => &mut self.a,
+            Part::B => &mut self.b,
+        }
+    }
+}
+
+impl PartAnswers {
+    /// Tightest bound given by rejected answers with the given hint
+    fn bound(&self, hint: Hint) -> Option<i128> {
+        let values = self
+            .incorrect
+            .iter()
+            .filter(|(_, h)| *h == Some(hint))
+            .filter_map(|(a, _)| a.parse::<i128>().ok());
+
+        match hint {
+            Hint::TooHigh => values.min(),
+            Hint::TooLow => values.max(),
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    #[error("answer was already accepted")]
+    AlreadyCorrect,
+    #[error("the accepted answer is {0}")]
+    DiffersFromCorrect(String),
+    #[error("answer was already rejected{}", match .0 {
+        Some(Hint::TooHigh) => " as too high",
+        Some(Hint::TooLow) => " as too low",
+        None => "",
+    })]
+    KnownIncorrect(Option<Hint>),
+    #[error("answer must be lower than {0}, which was too high")]
+    TooHigh(i128),
+    #[error("answer must be higher than {0}, which was too low")]
+    TooLow(i128),
+}
+
+/// Records a server verdict in the ledger of the given day
+pub fn record(
+    year: u32,
+    day: u32,
+    part: Part,
+    answer: &str,
+    verdict: Verdict,
+) -> Result<(), GetError> {
+    let mut answers = Answers::load(year, day)?;
+    if answers.record(part, answer, verdict) {
+        answers.save(year, day)?;
+    }
+
+    Ok(())
+}
+
+fn parse_line(line: &str) -> Option<(Part, Verdict, &str)> {
+    let mut split = line.splitn(3, ' ');
+    let part = split.next()?.parse().ok()?;
+    let verdict = match split.next()? {
+        "correct" => Verdict::Correct,
+        "incorrect" => Verdict::Incorrect(None),
+        "too-high" => Verdict::Incorrect(Some(Hint::TooHigh)),
+        "too-low" => Verdict::Incorrect(Some(Hint::TooLow)),
+        _ => return None,
+    };
+    let answer = split.next()?;
+    Some((part, verdict, answer))
+}
+
+fn answers_file_path(year: u32, day: u32) -> Result<PathBuf, std::env::VarError> {
+    crate::get::input_dir_path(&format!("{year}-{day}.answers"))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{Answers, Refusal};
+    use crate::{
+        solution::Part,
+        submit::{Hint, Verdict},
+    };
+
+    fn ledger() -> Answers {
+        let mut answers = Answers::default();
+        answers.record(Part::A, "100", Verdict::Incorrect(Some(Hint::TooHigh)));
+        answers.record(Part::A, "90", Verdict::Incorrect(Some(Hint::TooHigh)));
+        answers.record(Part::A, "10", Verdict::Incorrect(Some(Hint::TooLow)));
+        answers.record(Part::A, "42", Verdict::Incorrect(None));
+        answers.record(Part::B, "7", Verdict::Correct);
+        answers
+    }
+
+    #[rstest]
+    #[case(Part::A, "50", Ok(()))]
+    #[case(Part::A, "42", Err(Refusal::KnownIncorrect(None)))]
+    #[case(Part::A, "100", Err(Refusal::KnownIncorrect(Some(Hint::TooHigh))))]
+    #[case(Part::A, "95", Err(Refusal::TooHigh(90)))]
+    #[case(Part::A, "10", Err(Refusal::KnownIncorrect(Some(Hint::TooLow))))]
+    #[case(Part::A, "3", Err(Refusal::TooLow(10)))]
+    #[case(Part::A, "abc", Ok(()))]
+    #[case(Part::B, "7", Err(Refusal::AlreadyCorrect))]
+    #[case(Part::B, "8", Err(Refusal::DiffersFromCorrect("7".to_string())))]
+    fn test_guard(#[case] part: Part, #[case] answer: &str, #[case] expected: Result<(), Refusal>) {
+        let result = ledger().guard(part, answer);
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,205 @@
+use std::time::{Duration, Instant};
+
+use crate::{
+    solution::{Part, RunEntry, SolutionErrors, Solutions},
+    solve::{SolveDays, SolveYear},
+};
+
+#[derive(clap::Args)]
+/// Benchmark solutions on your input files
+pub struct BenchCli {
+    #[arg()]
+    /// "all", "latest", "today", a year or a range of years like 2015..2025
+    year: SolveYear,
+    #[arg()]
+    /// Days or ranges of days like 1-5,7
+    day: Option<SolveDays>,
+    #[arg(long, default_value_t = 3)]
+    /// Number of untimed runs before measuring
+    warmup: u32,
+    #[arg(long, short = 'n')]
+    /// Number of timed runs; overrides the time budget
+    runs: Option<u32>,
+    #[arg(long, default_value_t = 1.0)]
+    /// Time budget per part, in seconds
+    budget: f64,
+}
+
+pub fn run_command(cli: BenchCli) -> anyhow::Result<()> {
+    let solutions = Solutions::default();
+    let limit = match cli.runs {
+        Some(runs) => Limit::Runs(runs.max(1)),
+        None => Limit::Budget(Duration::try_from_secs_f64(cli.budget)?),
+    };
+
+    let entries = crate::solve::select(&solutions, &cli.year, cli.day.as_ref())?;
+    bench_entries(entries, cli.warmup, limit)
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Limit {
+    Runs(u32),
+    Budget(Duration),
+}
+
+fn bench_entries<'a>(
+    entries: impl IntoIterator<Item = RunEntry<'a>>,
+    warmup: u32,
+    limit: Limit,
+) -> anyhow::Result<()> {
+    use crate::util::style::{Color, ToStyled as _};
+
+    let mut error_count = 0;
+
+    for ((year, day), solution) in entries {
+        let input = crate::get::get_input(year, day)?;
+        let input = solution.prepare(&input)?;
+        let params = solution.params(&[])?;
+
+        if let Some(parse) = solution.parser() {
+            print!("{year}-{day} parse:");
+            match bench(|| parse(&input).map(|(_, time)| time), warmup, limit) {
+                Ok(stats) => println!(" {stats}"),
+                Err(error) => {
+                    error_count += 1;
+                    let error = format!("{error}");
+                    println!(" {}", error.with_fg(Color::Red));
+                    continue;
+                }
+            }
+        }
+
+        let parsed = solution.parse(&input)?;
+        for part in Part::ALL {
+            let Some(f) = solution.part(part) else {
+                continue;
+            };
+
+            print!("{year}-{day} {part}:");
+            let run = || f(&input, &parsed, &params).map(|(_, time)| time);
+            match bench(run, warmup, limit) {
+                Ok(stats) => println!(" {stats}"),
+                Err(error) => {
+                    error_count += 1;
+                    let error = format!("{error}");
+                    println!(" {}", error.with_fg(Color::Red));
+                }
+            }
+        }
+    }
+
+    if error_count == 0 {
+        Ok(())
+    } else {
+        Err(SolutionErrors(error_count).into())
+    }
+}
+
+/// Measures a timed function, which returns the duration of its own work
+fn bench(
+    mut f: impl FnMut() -> anyhow::Result<Duration>,
+    warmup: u32,
+    limit: Limit,
+) -> anyhow::Result<Stats> {
+    for _ in 0..warmup {
+        f()?;
+    }
+
+    let mut samples = Vec::new();
+    let start = Instant::now();
+    loop {
+        let time = f()?;
+        samples.push(time);
+
+        let done = match limit {
+            Limit::Runs(runs) => samples.len() >= runs as usize,
+            Limit::Budget(budget) => start.elapsed() >= budget,
+        };
+
+        if done {
+            break;
+        }
+    }
+
+    Ok(Stats::new(samples))
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Stats {
+    runs: usize,
+    min: Duration,
+    median: Duration,
+    mean: Duration,
+    stddev: Duration,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+
+        let runs = samples.len();
+        let min = samples[0];
+        let median = if runs.is_multiple_of(2) {
+            (samples[runs / 2 - 1] + samples[runs / 2]) / 2
+        } else {
+            samples[runs / 2]
+        };
+
+        let mean = samples.iter().map(Duration::as_secs_f64).sum::<f64>() / runs as f64;
+        let variance = samples
+            .iter()
+            .map(|s| (s.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / runs as f64;
+
+        Self {
+            runs,
+            min,
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+impl std::fmt::Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:?}, median {:?}, mean {:?} ± {:?} ({} runs)",
+            self.min, self.median, self.mean, self.stddev, self.runs
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(&[3], 3000, 3000, 3.0, 0.0)]
+    #[case(&[5, 1, 3], 1000, 3000, 3.0, 1.632993)]
+    #[case(&[4, 1, 3, 2], 1000, 2500, 2.5, 1.118034)]
+    #[case(&[2, 4, 4, 4, 5, 5, 7, 9], 2000, 4500, 5.0, 2.0)]
+    fn test_stats(
+        #[case] samples_ms: &[u64],
+        #[case] min_us: u64,
+        #[case] median_us: u64,
+        #[case] mean_ms: f64,
+        #[case] stddev_ms: f64,
+    ) {
+        let samples = samples_ms
+            .iter()
+            .copied()
+            .map(Duration::from_millis)
+            .collect();
+        let stats = super::Stats::new(samples);
+        assert_eq!(stats.runs, samples_ms.len());
+        assert_eq!(stats.min, Duration::from_micros(min_us));
+        assert_eq!(stats.median, Duration::from_micros(median_us));
+        assert!((stats.mean.as_secs_f64() * 1000.0 - mean_ms).abs() < 1e-6);
+        assert!((stats.stddev.as_secs_f64() * 1000.0 - stddev_ms).abs() < 1e-6);
+    }
+}
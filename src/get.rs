@@ -1,12 +1,19 @@
 use std::{
     env, io,
+    io::Write as _,
     path::{Path, PathBuf},
     sync::Mutex,
+    time::{Duration, SystemTime},
 };
 
-const ADVENT_OF_CODE_URI: &str = "https://adventofcode.com";
+use crate::{http::Content, profile::Profile};
+
+pub const ADVENT_OF_CODE_URI: &str = "https://adventofcode.com";
 const SESSION_VAR: &str = "ADVENT_SESSION";
 const INPUT_DIR_VAR: &str = "ADVENT_INPUT_DIR";
+const CONTACT_VAR: &str = "ADVENT_CONTACT";
+const USER_AGENT: &str = "github.com/medeaxiv/advent";
+const WAIT_RETRIES: u32 = 10;
 
 #[derive(clap::Args)]
 /// Fetch your input files
@@ -14,6 +21,12 @@ pub struct GetCli {
     #[arg(long)]
     /// Print the input to standard output
     print: bool,
+    #[arg(long)]
+    /// Also extract the examples from the puzzle page
+    examples: bool,
+    #[arg(long)]
+    /// Wait for the puzzle to unlock, then fetch the input with retries
+    wait: bool,
     #[arg()]
     year: u32,
     #[arg()]
@@ -21,12 +34,21 @@ pub struct GetCli {
 }
 
 pub fn run_command(cli: GetCli) -> anyhow::Result<()> {
-    let input = fetch_input(cli.year, cli.day)?;
+    let input = if cli.wait {
+        wait_for_input(cli.year, cli.day)?
+    } else {
+        fetch_input(cli.year, cli.day)?
+    };
 
     if cli.print {
         println!("{input}");
     }
 
+    if cli.examples {
+        let examples = fetch_examples(cli.year, cli.day)?;
+        println!("{} examples saved", examples.len());
+    }
+
     Ok(())
 }
 
@@ -60,9 +82,10 @@ pub fn fetch_input(year: u32, day: u32) -> Result<String, GetError> {
     }
 
     // Fetch input file
-    let client = client()?;
-    let mut response = client.get(url).call()?;
-    let contents = response.body_mut().read_to_string()?;
+    let contents = match crate::http::get(&url, Content::Input, None) {
+        Err(GetError::NotFound) => return Err(GetError::NotUnlocked { year, day }),
+        result => result?,
+    };
 
     // Save input file locally
     std::fs::write(&path, contents.as_bytes())?;
@@ -70,10 +93,197 @@ pub fn fetch_input(year: u32, day: u32) -> Result<String, GetError> {
     Ok(contents)
 }
 
-fn input_file_path(year: u32, day: u32) -> Result<PathBuf, env::VarError> {
-    let dir = env::var(INPUT_DIR_VAR)?;
-    let file = format!("{year}-{day}.txt");
-    let path = Path::new(&dir).join(&file);
+/// Waits for the puzzle to unlock while showing a countdown, then fetches the input
+pub fn wait_for_input(year: u32, day: u32) -> Result<String, GetError> {
+    let unlock = unlock_time(year, day).ok_or(GetError::InvalidDay { year, day })?;
+    // A missing session should be reported now, not once the puzzle unlocks
+    client()?;
+
+    while let Ok(remaining) = unlock.duration_since(SystemTime::now()) {
+        let secs = remaining.as_secs();
+        eprint!(
+            "\rUnlocks in {:02}:{:02}:{:02} ",
+            secs / 3600,
+            secs / 60 % 60,
+            secs % 60
+        );
+        let _ = io::stderr().flush();
+        std::thread::sleep(remaining.min(Duration::from_secs(1)));
+    }
+    eprintln!();
+
+    let mut retry = 0;
+    loop {
+        match fetch_input(year, day) {
+            Err(
+                error @ (GetError::NotUnlocked { .. }
+                | GetError::ServerError(_)
+                | GetError::Http(_)),
+            ) if retry < WAIT_RETRIES => {
+                retry += 1;
+                tracing::warn!(%error, retry, "Unable to fetch input; retrying");
+                std::thread::sleep(Duration::from_secs(retry as u64));
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Puzzles unlock at midnight US Eastern time (UTC-5) in December
+pub fn unlock_time(year: u32, day: u32) -> Option<SystemTime> {
+    if year < 2015 || !(1..=days_in_event(year)).contains(&day) {
+        return None;
+    }
+
+    let days = days_from_civil(year as i64, 12, day as i64);
+    let secs = days * 86400 + 5 * 3600;
+    let secs = u64::try_from(secs).ok()?;
+    Some(SystemTime::UNIX_EPOCH + Duration::from_secs(secs))
+}
+
+/// Number of puzzles in an event; events from 2025 on are 12 days long
+pub fn days_in_event(year: u32) -> u32 {
+    if year >= 2025 { 12 } else { 25 }
+}
+
+/// Number of days between 1970-01-01 and the given date of the proleptic Gregorian calendar
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// The current date in US Eastern time (UTC-5), which is the time zone of the AoC calendar
+pub fn today() -> (u32, u32, u32) {
+    let secs = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() as i64);
+    civil_from_days((secs - 5 * 3600).div_euclid(86400))
+}
+
+/// Year of the most recent event that has started
+pub fn latest_event() -> u32 {
+    match today() {
+        (year, 12, _) => year,
+        (year, _, _) => year - 1,
+    }
+}
+
+/// Inverse of [`days_from_civil`]
+fn civil_from_days(days: i64) -> (u32, u32, u32) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + (month <= 2) as i64;
+    (year as u32, month as u32, day as u32)
+}
+
+/// Reads the numbered example files saved by [`fetch_examples`]
+pub fn read_examples(year: u32, day: u32) -> Result<Vec<String>, GetError> {
+    let mut examples = Vec::new();
+    for index in 1.. {
+        let path = example_file_path(year, day, index)?;
+        match std::fs::read_to_string(path) {
+            Ok(contents) => examples.push(contents),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => break,
+            Err(error) => return Err(error.into()),
+        }
+    }
+
+    Ok(examples)
+}
+
+/// Extracts the `<pre><code>` blocks of the puzzle page into numbered example files
+pub fn fetch_examples(year: u32, day: u32) -> Result<Vec<String>, GetError> {
+    use crate::util::html;
+
+    let page = get_puzzle(year, day, false)?;
+    let examples: Vec<_> = html::elements(&page, "<pre><code>", "</code></pre>")
+        .map(|block| html::decode_entities(&html::strip_tags(block)).into_owned())
+        .collect();
+
+    for (index, example) in examples.iter().enumerate() {
+        let path = example_file_path(year, day, index + 1)?;
+        std::fs::write(&path, example.as_bytes())?;
+    }
+
+    // Examples left over from an earlier fetch would be read along with the new ones
+    for index in examples.len() + 1.. {
+        let path = example_file_path(year, day, index)?;
+        match std::fs::remove_file(path) {
+            Ok(()) => {}
+            Err(error) if error.kind() == io::ErrorKind::NotFound => break,
+            Err(error) => return Err(error.into()),
+        }
+    }
+
+    Ok(examples)
+}
+
+/// Reads the puzzle page from the local cache, fetching it when missing or when `refresh` is set
+pub fn get_puzzle(year: u32, day: u32, refresh: bool) -> Result<String, GetError> {
+    let path = puzzle_file_path(year, day)?;
+    if !refresh && let Ok(contents) = std::fs::read_to_string(&path) {
+        tracing::trace!(year, day, "Puzzle read from local file");
+        return Ok(contents);
+    }
+
+    fetch_puzzle(year, day)
+}
+
+pub fn fetch_puzzle(year: u32, day: u32) -> Result<String, GetError> {
+    let path = puzzle_file_path(year, day)?;
+    let url = puzzle_url(year, day);
+
+    // Ensure input directory exists
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    let contents = match crate::http::get(&url, Content::Page, None) {
+        Err(GetError::NotFound) => return Err(GetError::NotUnlocked { year, day }),
+        result => result?,
+    };
+
+    // Save puzzle page locally
+    std::fs::write(&path, contents.as_bytes())?;
+
+    Ok(contents)
+}
+
+fn puzzle_file_path(year: u32, day: u32) -> Result<PathBuf, env::VarError> {
+    input_dir_path(&format!("{year}-{day}.puzzle.html"))
+}
+
+fn example_file_path(year: u32, day: u32, index: usize) -> Result<PathBuf, env::VarError> {
+    input_dir_path(&format!("{year}-{day}.example{index}.txt"))
+}
+
+pub fn input_file_path(year: u32, day: u32) -> Result<PathBuf, env::VarError> {
+    input_dir_path(&format!("{year}-{day}.txt"))
+}
+
+/// Resolves a file name inside the input directory
+pub fn input_dir_path(file: &str) -> Result<PathBuf, env::VarError> {
+    let dir = match crate::profile::active() {
+        Some(Profile {
+            input_dir: Some(dir),
+            ..
+        }) => PathBuf::from(dir),
+        // Inputs, answers and caches belong to an account, so profiles never share a directory
+        Some(profile) => Path::new(&env::var(INPUT_DIR_VAR)?).join(profile.name),
+        None => PathBuf::from(env::var(INPUT_DIR_VAR)?),
+    };
+    let path = dir.join(file);
     Ok(path)
 }
 
@@ -81,30 +291,50 @@ fn input_file_url(year: u32, day: u32) -> String {
     format!("{ADVENT_OF_CODE_URI}/{year}/day/{day}/input")
 }
 
-fn client() -> Result<ureq::Agent, GetError> {
-    static AGENT: Mutex<Option<ureq::Agent>> = Mutex::new(None);
+fn puzzle_url(year: u32, day: u32) -> String {
+    format!("{ADVENT_OF_CODE_URI}/{year}/day/{day}")
+}
+
+pub fn client() -> Result<ureq::Agent, GetError> {
+    static AGENT: Mutex<Option<(String, ureq::Agent)>> = Mutex::new(None);
+
+    let session = match crate::profile::active().and_then(|profile| profile.session) {
+        Some(session) => session,
+        None => env::var(SESSION_VAR).map_err(|_| GetError::MissingSession)?,
+    };
 
+    // The agent is rebuilt whenever the active profile changes the session
     let mut lock = AGENT.lock().unwrap();
-    if let Some(agent) = lock.as_ref() {
+    if let Some((cached, agent)) = lock.as_ref()
+        && *cached == session
+    {
         return Ok(agent.clone());
     }
 
+    // The AoC automation guidelines ask for a way to contact the tool's user
+    let user_agent = match env::var(CONTACT_VAR) {
+        Ok(contact) => format!("{USER_AGENT} by {contact}"),
+        Err(_) => USER_AGENT.to_string(),
+    };
+
+    // Error statuses are classified by the request layer, which needs to inspect their bodies
     let agent: ureq::Agent = ureq::Agent::config_builder()
         .https_only(true)
+        .http_status_as_error(false)
         .accept("text/plain")
+        .user_agent(user_agent)
         .build()
         .into();
 
     {
         let uri = ureq::http::Uri::from_static(ADVENT_OF_CODE_URI);
-        let session = env::var(SESSION_VAR)?;
         let cookie = format!("session={session}");
         let cookie = ureq::Cookie::parse(cookie, &uri)?;
         let mut cookie_jar = agent.cookie_jar_lock();
         cookie_jar.insert(cookie, &uri)?;
     }
 
-    *lock = Some(agent.clone());
+    *lock = Some((session, agent.clone()));
     Ok(agent)
 }
 
@@ -116,4 +346,50 @@ pub enum GetError {
     Io(#[from] io::Error),
     #[error(transparent)]
     Http(#[from] ureq::Error),
+    #[error("puzzle {year}-{day} is not unlocked yet")]
+    NotUnlocked { year: u32, day: u32 },
+    #[error("the {SESSION_VAR} variable is not set")]
+    MissingSession,
+    #[error("the session cookie is invalid or expired")]
+    BadSession,
+    #[error("page not found")]
+    NotFound,
+    #[error("the AoC server failed with status {0}")]
+    ServerError(u16),
+    #[error("unexpected status {0} from the AoC server")]
+    UnexpectedStatus(u16),
+    #[error("unexpected response body from the AoC server")]
+    UnexpectedBody,
+    #[error("there is no puzzle {year}-{day}")]
+    InvalidDay { year: u32, day: u32 },
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(2025, 1, Some(1764565200))]
+    #[case(2024, 25, Some(1735102800))]
+    #[case(2025, 20, None)]
+    #[case(2024, 0, None)]
+    #[case(2010, 1, None)]
+    fn test_unlock_time(#[case] year: u32, #[case] day: u32, #[case] expected: Option<u64>) {
+        let result = super::unlock_time(year, day);
+        let expected = expected.map(|secs| SystemTime::UNIX_EPOCH + Duration::from_secs(secs));
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case(0, (1970, 1, 1))]
+    #[case(20423, (2025, 12, 1))]
+    #[case(11016, (2000, 2, 29))]
+    fn test_civil_from_days(#[case] days: i64, #[case] expected: (u32, u32, u32)) {
+        let result = super::civil_from_days(days);
+        assert_eq!(result, expected);
+        let (y, m, d) = expected;
+        assert_eq!(super::days_from_civil(y as i64, m as i64, d as i64), days);
+    }
 }
@@ -0,0 +1,174 @@
+//! Timing history, stored next to the input files as `timings-{machine}.csv`
+//!
+//! Each line records one timed part as `<unix time>,<commit>,<year>,<day>,<part>,<nanoseconds>`.
+
+use std::{
+    env, io,
+    io::Write as _,
+    path::PathBuf,
+    time::{Duration, SystemTime},
+};
+
+use foldhash::HashMap;
+
+use crate::{get::GetError, solution::Part};
+
+const MACHINE_VAR: &str = "ADVENT_MACHINE";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub commit: String,
+    pub time: Duration,
+}
+
+#[derive(Debug, Default)]
+pub struct History {
+    baselines: HashMap<(u32, u32, Part), Record>,
+}
+
+impl History {
+    pub fn load() -> Result<Self, GetError> {
+        let path = history_file_path()?;
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(error) => return Err(error.into()),
+        };
+
+        let mut baselines = HashMap::default();
+        for line in contents.lines() {
+            let Some((key, record)) = parse_line(line) else {
+                tracing::warn!(line, "Skipping invalid timing history line");
+                continue;
+            };
+
+            baselines.insert(key, record);
+        }
+
+        Ok(Self { baselines })
+    }
+
+    /// The most recent record for a part
+    pub fn baseline(&self, year: u32, day: u32, part: Part) -> Option<&Record> {
+        self.baselines.get(&(year, day, part))
+    }
+}
+
+pub fn append(timings: &[(u32, u32, Part, Duration)]) -> Result<(), GetError> {
+    if timings.is_empty() {
+        return Ok(());
+    }
+
+    let path = history_file_path()?;
+    let timestamp = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let commit = current_commit();
+
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+
+    for (year, day, part, time) in timings {
+        let nanos = time.as_nanos();
+        writeln!(file, "{timestamp},{commit},{year},{day},{part},{nanos}")?;
+    }
+
+    Ok(())
+}
+
+fn parse_line(line: &str) -> Option<((u32, u32, Part), Record)> {
+    let mut split = line.split(',');
+    let _timestamp: u64 = split.next()?.parse().ok()?;
+    let commit = split.next()?.to_string();
+    let year = split.next()?.parse().ok()?;
+    let day = split.next()?.parse().ok()?;
+    let part = split.next()?.parse().ok()?;
+    let nanos: u64 = split.next()?.parse().ok()?;
+
+    let record = Record {
+        commit,
+        time: Duration::from_nanos(nanos),
+    };
+    Some(((year, day, part), record))
+}
+
+fn current_commit() -> String {
+    std::process::Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|commit| commit.trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+fn machine_name() -> String {
+    env::var(MACHINE_VAR)
+        .or_else(|_| env::var("HOSTNAME"))
+        .ok()
+        .or_else(|| std::fs::read_to_string("/etc/hostname").ok())
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "local".to_string())
+}
+
+fn history_file_path() -> Result<PathBuf, env::VarError> {
+    crate::get::input_dir_path(&format!("timings-{}.csv", machine_name()))
+}
+
+pub fn print_comparison(time: Duration, baseline: Option<&Record>, threshold: f64) {
+    use crate::util::style::{Color, ToStyled as _};
+
+    // A part too fast to measure has no meaningful relative change
+    let Some(baseline) = baseline.filter(|baseline| !baseline.time.is_zero()) else {
+        println!("  {}", "no baseline".with_fg(Color::BrightBlack));
+        return;
+    };
+
+    let change = (time.as_secs_f64() / baseline.time.as_secs_f64() - 1.0) * 100.0;
+    let color = if change > threshold {
+        Color::Red
+    } else if change < -threshold {
+        Color::Green
+    } else {
+        Color::Default
+    };
+
+    let change = format!("{change:+.1}%");
+    println!(
+        "  {change} vs {:?} ({})",
+        baseline.time,
+        baseline.commit,
+        change = change.with_fg(color)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use rstest::rstest;
+
+    use super::Record;
+    use crate::solution::Part;
+
+    #[rstest]
+    #[case("1764565200,abc1234,2025,8,A,1500", Some((2025, 8, Part::A, "abc1234", 1500)))]
+    #[case("1764565200,unknown,2024,25,B,0", Some((2024, 25, Part::B, "unknown", 0)))]
+    #[case("1764565200,abc1234,2025,8,C,1500", None)]
+    #[case("1764565200,abc1234,2025,8,A", None)]
+    #[case("", None)]
+    fn test_parse_line(#[case] line: &str, #[case] expected: Option<(u32, u32, Part, &str, u64)>) {
+        let expected = expected.map(|(year, day, part, commit, nanos)| {
+            let record = Record {
+                commit: commit.to_string(),
+                time: Duration::from_nanos(nanos),
+            };
+            ((year, day, part), record)
+        });
+        assert_eq!(super::parse_line(line), expected);
+    }
+}
@@ -0,0 +1,231 @@
+//! Polite request layer in front of the AoC server
+//!
+//! Requests are spaced by a minimum interval that persists across runs, and fetched pages are
+//! cached in the `cache` subdirectory of the input directory.
+
+use std::{
+    env, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use crate::get::{ADVENT_OF_CODE_URI, GetError};
+
+const MIN_INTERVAL_VAR: &str = "ADVENT_MIN_INTERVAL";
+const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Kind of content expected from a request, which decides the checks applied to the body
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Content {
+    /// A puzzle input, never an HTML page
+    Input,
+    /// An HTML page
+    Page,
+    /// A JSON document
+    Json,
+}
+
+impl Content {
+    fn accept(self) -> &'static str {
+        match self {
+            Self::Input => "text/plain",
+            Self::Page => "text/html",
+            Self::Json => "application/json",
+        }
+    }
+}
+
+/// Fetches a page, reusing the cached copy if it is younger than `max_age`
+pub fn get(url: &str, content: Content, max_age: Option<Duration>) -> Result<String, GetError> {
+    let cache_path = cache_file_path(url)?;
+    if let Some(max_age) = max_age
+        && let Some((contents, age)) = read_cache(&cache_path)?
+        && age < max_age
+    {
+        tracing::trace!(url, ?age, "Page read from cache");
+        return Ok(contents);
+    }
+
+    throttle()?;
+    let client = crate::get::client()?;
+    let mut response = client.get(url).header("Accept", content.accept()).call()?;
+    let status = response.status().as_u16();
+    let contents = response.body_mut().read_to_string()?;
+    classify(status, &contents)?;
+    // Checked before caching, so that an error page is not served from the cache later on
+    check_body(content, &contents)?;
+
+    if let Some(dir) = cache_path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(&cache_path, contents.as_bytes())?;
+
+    Ok(contents)
+}
+
+pub fn post_form(url: &str, form: &[(&str, &str)]) -> Result<String, GetError> {
+    throttle()?;
+    let client = crate::get::client()?;
+    let mut response = client
+        .post(url)
+        .header("Accept", Content::Page.accept())
+        .send_form(form.iter().copied())?;
+    let status = response.status().as_u16();
+    let contents = response.body_mut().read_to_string()?;
+    classify(status, &contents)?;
+    Ok(contents)
+}
+
+/// Turns error responses into typed errors
+fn classify(status: u16, body: &str) -> Result<(), GetError> {
+    match status {
+        200..300 => Ok(()),
+        400 | 401 | 403 if body.contains("log in") => Err(GetError::BadSession),
+        404 => Err(GetError::NotFound),
+        500..600 => Err(GetError::ServerError(status)),
+        _ => Err(GetError::UnexpectedStatus(status)),
+    }
+}
+
+/// Detects error pages served with a 200 in place of the expected content
+fn check_body(content: Content, body: &str) -> Result<(), GetError> {
+    let valid = match content {
+        Content::Input => !looks_like_html(body),
+        Content::Page => true,
+        // Pages the session cannot access redirect to an HTML page
+        Content::Json => serde_json::from_str::<serde::de::IgnoredAny>(body).is_ok(),
+    };
+
+    match (valid, body.contains("Please log in")) {
+        (true, _) => Ok(()),
+        (false, true) => Err(GetError::BadSession),
+        (false, false) => Err(GetError::UnexpectedBody),
+    }
+}
+
+pub fn looks_like_html(body: &str) -> bool {
+    let start = body.trim_start();
+    let start = start.get(..64).unwrap_or(start);
+    start.starts_with('<') && start.to_ascii_lowercase().contains("html")
+}
+
+/// The cached copy of a page and the time since it was fetched
+fn read_cache(path: &Path) -> Result<Option<(String, Duration)>, GetError> {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(error) => return Err(error.into()),
+    };
+
+    let modified = std::fs::metadata(path)?.modified()?;
+    let age = modified.elapsed().unwrap_or_default();
+    Ok(Some((contents, age)))
+}
+
+/// Waits until the minimum interval since the last request, from any run, has elapsed
+fn throttle() -> Result<(), GetError> {
+    let path = crate::get::input_dir_path(".last-request")?;
+    let interval = min_interval();
+
+    let last = std::fs::read_to_string(&path)
+        .ok()
+        .and_then(|contents| contents.trim().parse::<u64>().ok())
+        .map(|millis| SystemTime::UNIX_EPOCH + Duration::from_millis(millis));
+
+    if let Some(elapsed) = last.and_then(|last| last.elapsed().ok())
+        && elapsed < interval
+    {
+        let wait = interval - elapsed;
+        tracing::debug!(?wait, "Throttling request");
+        std::thread::sleep(wait);
+    }
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis());
+    std::fs::write(&path, now.to_string())?;
+    Ok(())
+}
+
+fn min_interval() -> Duration {
+    env::var(MIN_INTERVAL_VAR)
+        .ok()
+        .and_then(|secs| secs.parse().ok())
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        .unwrap_or(DEFAULT_MIN_INTERVAL)
+}
+
+fn cache_file_path(url: &str) -> Result<PathBuf, env::VarError> {
+    let path = url.strip_prefix(ADVENT_OF_CODE_URI).unwrap_or(url);
+    let file: String = path
+        .trim_matches('/')
+        .chars()
+        .map(|ch| if ch.is_ascii_alphanumeric() { ch } else { '_' })
+        .collect();
+    crate::get::input_dir_path(&format!("cache/{file}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::Content;
+    use crate::get::GetError;
+
+    #[rstest]
+    #[case(
+        400,
+        "Puzzle inputs differ by user.  Please log in to get your puzzle input."
+    )]
+    #[case(404, "404 Not Found")]
+    #[case(500, "Internal Server Error")]
+    #[case(418, "")]
+    fn test_classify_error(#[case] status: u16, #[case] body: &str) {
+        let result = super::classify(status, body).unwrap_err();
+        let matches = match status {
+            400 => matches!(result, GetError::BadSession),
+            404 => matches!(result, GetError::NotFound),
+            500 => matches!(result, GetError::ServerError(500)),
+            _ => matches!(result, GetError::UnexpectedStatus(418)),
+        };
+        assert!(matches, "unexpected classification {result:?}");
+    }
+
+    #[rstest]
+    #[case("<!DOCTYPE html>\n<html lang=\"en-us\">", true)]
+    #[case("1,2,3\n4,5,6\n", false)]
+    #[case("<.>\n<..>\n", false)]
+    fn test_looks_like_html(#[case] body: &str, #[case] expected: bool) {
+        assert_eq!(super::looks_like_html(body), expected);
+    }
+
+    #[rstest]
+    #[case(Content::Input, "1,2,3\n", None)]
+    #[case(
+        Content::Input,
+        "<!DOCTYPE html>\n<html>Please log in</html>",
+        Some(GetError::BadSession)
+    )]
+    #[case(Content::Page, "<!DOCTYPE html>\n<html></html>", None)]
+    #[case(Content::Json, "{\"members\":{}}", None)]
+    #[case(
+        Content::Json,
+        "<!DOCTYPE html>\n<html></html>",
+        Some(GetError::UnexpectedBody)
+    )]
+    fn test_check_body(
+        #[case] content: Content,
+        #[case] body: &str,
+        #[case] expected: Option<GetError>,
+    ) {
+        let result = super::check_body(content, body).err();
+        assert_eq!(
+            result.map(|e| e.to_string()),
+            expected.map(|e| e.to_string())
+        );
+    }
+}
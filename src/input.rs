@@ -0,0 +1,100 @@
+//! Checks and normalisation applied to an input before the solutions see it
+
+use std::borrow::Cow;
+
+bitflags::bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Normalize: u8 {
+        /// Convert CRLF line endings to LF
+        const LINE_ENDINGS = 1 << 0;
+        /// Strip the line ending at the end of the input
+        const TRIM_END = 1 << 1;
+        /// Reject empty inputs, HTML pages and byte order marks
+        const CHECK = 1 << 2;
+    }
+}
+
+impl Default for Normalize {
+    fn default() -> Self {
+        Self::LINE_ENDINGS | Self::CHECK
+    }
+}
+
+pub fn normalize(input: &str, flags: Normalize) -> Result<Cow<'_, str>, InputError> {
+    if flags.contains(Normalize::CHECK) {
+        check(input)?;
+    }
+
+    let mut input = Cow::Borrowed(input);
+    if flags.contains(Normalize::LINE_ENDINGS) && input.contains('\r') {
+        input = Cow::Owned(input.replace("\r\n", "\n"));
+    }
+
+    if flags.contains(Normalize::TRIM_END) {
+        let trimmed = input.strip_suffix('\n').unwrap_or(&input);
+        let len = trimmed.strip_suffix('\r').unwrap_or(trimmed).len();
+        input = match input {
+            Cow::Borrowed(input) => Cow::Borrowed(&input[..len]),
+            Cow::Owned(mut input) => {
+                input.truncate(len);
+                Cow::Owned(input)
+            }
+        };
+    }
+
+    Ok(input)
+}
+
+fn check(input: &str) -> Result<(), InputError> {
+    if input.starts_with('\u{feff}') {
+        return Err(InputError::ByteOrderMark);
+    }
+
+    if input.trim().is_empty() {
+        return Err(InputError::Empty);
+    }
+
+    if crate::http::looks_like_html(input) {
+        return Err(InputError::Html);
+    }
+
+    Ok(())
+}
+
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputError {
+    #[error("input is empty")]
+    Empty,
+    #[error("input is an HTML page; the input file was probably saved from an error page")]
+    Html,
+    #[error("input starts with a byte order mark")]
+    ByteOrderMark,
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{InputError, Normalize};
+
+    #[rstest]
+    #[case("1\n2\n", Normalize::default(), "1\n2\n")]
+    #[case("1\r\n2\r\n", Normalize::default(), "1\n2\n")]
+    #[case("1\n2\n", Normalize::default() | Normalize::TRIM_END, "1\n2")]
+    #[case("1\r\n2\r\n", Normalize::TRIM_END, "1\r\n2")]
+    #[case("1\n2", Normalize::TRIM_END, "1\n2")]
+    fn test_normalize(#[case] input: &str, #[case] flags: Normalize, #[case] expected: &str) {
+        let result = super::normalize(input, flags).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case("", InputError::Empty)]
+    #[case("\n\n", InputError::Empty)]
+    #[case("\u{feff}1\n2\n", InputError::ByteOrderMark)]
+    #[case("<!DOCTYPE html>\n<html></html>\n", InputError::Html)]
+    fn test_check(#[case] input: &str, #[case] expected: InputError) {
+        let result = super::normalize(input, Normalize::default());
+        assert_eq!(result, Err(expected));
+    }
+}
@@ -0,0 +1,212 @@
+use std::{collections::BTreeMap, io, path::PathBuf, time::Duration};
+
+use crate::{
+    get::{ADVENT_OF_CODE_URI, GetError},
+    http::Content,
+    util::style::{Color, ToStyled as _},
+};
+
+/// The AoC automation guidelines ask for private leaderboards to be fetched at most every 15 minutes
+const MAX_AGE: Duration = Duration::from_secs(15 * 60);
+
+#[derive(clap::Args)]
+/// Show a private leaderboard
+pub struct LeaderboardCli {
+    #[arg(long)]
+    /// Event year; defaults to the latest event
+    year: Option<u32>,
+    #[arg(long)]
+    /// Show star timestamps for a day
+    day: Option<u32>,
+    #[arg(long)]
+    /// Show the stars earned since the last check
+    diff: bool,
+    #[arg()]
+    id: u64,
+}
+
+pub fn run_command(cli: LeaderboardCli) -> anyhow::Result<()> {
+    let year = cli.year.unwrap_or_else(crate::get::latest_event);
+
+    let previous = read_snapshot(year, cli.id)?;
+    let leaderboard = fetch_leaderboard(year, cli.id)?;
+    write_snapshot(year, cli.id, &leaderboard)?;
+
+    let members = leaderboard.ranked();
+    match cli.day {
+        Some(day) => print_day(year, day, &members),
+        None => print_table(year, &members),
+    }
+
+    if cli.diff {
+        match previous {
+            Some(previous) => print_diff(&previous, &members),
+            None => println!("{}", "no previous snapshot".with_fg(Color::BrightBlack)),
+        }
+    }
+
+    Ok(())
+}
+
+fn fetch_leaderboard(year: u32, id: u64) -> Result<Leaderboard, GetError> {
+    let url = leaderboard_url(year, id);
+    let contents = crate::http::get(&url, Content::Json, Some(MAX_AGE))?;
+    serde_json::from_str(&contents).map_err(|_| GetError::UnexpectedBody)
+}
+
+fn read_snapshot(year: u32, id: u64) -> Result<Option<Leaderboard>, GetError> {
+    let path = snapshot_file_path(year, id)?;
+    match std::fs::read_to_string(path) {
+        Ok(contents) => Ok(serde_json::from_str(&contents).ok()),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(error.into()),
+    }
+}
+
+fn write_snapshot(year: u32, id: u64, leaderboard: &Leaderboard) -> Result<(), GetError> {
+    let path = snapshot_file_path(year, id)?;
+    let contents = serde_json::to_string(leaderboard).map_err(io::Error::from)?;
+    std::fs::write(path, contents)?;
+    Ok(())
+}
+
+fn print_table(year: u32, members: &[&Member]) {
+    let days = crate::get::days_in_event(year);
+    crate::list::print_days_header(days);
+
+    for (rank, member) in members.iter().enumerate() {
+        print!("{:3}) {:5} ", rank + 1, member.local_score);
+        for day in 1..=days {
+            match member.stars_on(day) {
+                2 => print!("{}", '*'.with_fg(Color::Yellow)),
+                1 => print!("{}", '*'.with_fg(Color::White)),
+                _ => print!("{}", '.'.with_fg(Color::BrightBlack)),
+            }
+        }
+        println!(" {}", member.display_name());
+    }
+}
+
+fn print_day(year: u32, day: u32, members: &[&Member]) {
+    let unlock = crate::get::unlock_time(year, day)
+        .and_then(|unlock| {
+            unlock
+                .duration_since(std::time::SystemTime::UNIX_EPOCH)
+                .ok()
+        })
+        .map_or(0, |unlock| unlock.as_secs());
+
+    let format = |timestamp: Option<u64>| match timestamp {
+        Some(timestamp) => {
+            let secs = timestamp.saturating_sub(unlock);
+            format!("{:>3}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+        }
+        None => format!("{:>9}", "-"),
+    };
+
+    println!("Day {day}    part 1    part 2");
+    for member in members {
+        let a = member.star_timestamp(day, 1);
+        let b = member.star_timestamp(day, 2);
+        if a.is_none() && b.is_none() {
+            continue;
+        }
+
+        println!(
+            "      {} {} {}",
+            format(a).with_fg(Color::White),
+            format(b).with_fg(Color::Yellow),
+            member.display_name()
+        );
+    }
+}
+
+fn print_diff(previous: &Leaderboard, members: &[&Member]) {
+    let mut changed = false;
+    for member in members {
+        let before = previous.members.get(&member.id.to_string());
+        let new_stars: Vec<_> = member
+            .stars()
+            .filter(|(day, part, _)| before.is_none_or(|b| b.star_timestamp(*day, *part).is_none()))
+            .map(|(day, part, _)| format!("{day}.{part}"))
+            .collect();
+
+        if new_stars.is_empty() {
+            continue;
+        }
+
+        changed = true;
+        let stars = format!("+{} ({})", new_stars.len(), new_stars.join(", "));
+        println!("{} {}", member.display_name(), stars.with_fg(Color::Green));
+    }
+
+    if !changed {
+        println!("{}", "no new stars".with_fg(Color::BrightBlack));
+    }
+}
+
+fn leaderboard_url(year: u32, id: u64) -> String {
+    format!("{ADVENT_OF_CODE_URI}/{year}/leaderboard/private/view/{id}.json")
+}
+
+fn snapshot_file_path(year: u32, id: u64) -> Result<PathBuf, std::env::VarError> {
+    crate::get::input_dir_path(&format!("leaderboard-{year}-{id}.json"))
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+struct Leaderboard {
+    members: BTreeMap<String, Member>,
+}
+
+impl Leaderboard {
+    fn ranked(&self) -> Vec<&Member> {
+        let mut members: Vec<_> = self.members.values().collect();
+        members.sort_by(|a, b| {
+            b.local_score
+                .cmp(&a.local_score)
+                .then(a.last_star_ts.cmp(&b.last_star_ts))
+        });
+        members
+    }
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+struct Member {
+    id: u64,
+    name: Option<String>,
+    local_score: u64,
+    last_star_ts: u64,
+    /// Stars by day then part
+    completion_day_level: BTreeMap<u32, BTreeMap<u32, Star>>,
+}
+
+impl Member {
+    fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    fn stars_on(&self, day: u32) -> usize {
+        self.completion_day_level.get(&day).map_or(0, BTreeMap::len)
+    }
+
+    fn star_timestamp(&self, day: u32, part: u32) -> Option<u64> {
+        let star = self.completion_day_level.get(&day)?.get(&part)?;
+        Some(star.get_star_ts)
+    }
+
+    fn stars(&self) -> impl Iterator<Item = (u32, u32, u64)> + '_ {
+        self.completion_day_level.iter().flat_map(|(day, parts)| {
+            parts
+                .iter()
+                .map(|(part, star)| (*day, *part, star.get_star_ts))
+        })
+    }
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+struct Star {
+    get_star_ts: u64,
+}
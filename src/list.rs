@@ -0,0 +1,88 @@
+use crate::{
+    answers::Answers,
+    solution::{Part, Solutions},
+    util::style::{Color, ToStyled as _},
+};
+
+#[derive(clap::Args)]
+/// Show implemented parts, cached inputs and recorded answers for each event
+pub struct ListCli {
+    #[arg()]
+    /// Only show this year
+    year: Option<u32>,
+}
+
+pub fn run_command(cli: ListCli) -> anyhow::Result<()> {
+    let solutions = Solutions::default();
+    let years: Vec<u32> = match cli.year {
+        Some(year) => vec![year],
+        None => solutions.years().map(|(year, _)| year).collect(),
+    };
+
+    for year in years {
+        print_year(&solutions, year);
+    }
+
+    println!(
+        "{}",
+        "# input cached, * answer recorded, + implemented without an answer"
+            .with_fg(Color::BrightBlack)
+    );
+    Ok(())
+}
+
+/// Prints the day numbers of an event vertically, above a grid with one column per day
+pub fn print_days_header(days: u32) {
+    let tens: String = (1..=days)
+        .map(|day| match day / 10 {
+            0 => ' ',
+            tens => char::from_digit(tens, 10).unwrap_or(' '),
+        })
+        .collect();
+    let units: String = (1..=days)
+        .map(|day| char::from_digit(day % 10, 10).unwrap_or(' '))
+        .collect();
+    println!("{:10} {tens}", "");
+    println!("{:10} {units}", "");
+}
+
+fn print_year(solutions: &Solutions, year: u32) {
+    let days = crate::get::days_in_event(year);
+    print_days_header(days);
+
+    let mut inputs = String::new();
+    let mut parts = [String::new(), String::new()];
+    let mut stars = 0;
+    for day in 1..=days {
+        let cached = crate::get::input_file_path(year, day).is_ok_and(|path| path.exists());
+        let cell = match cached {
+            true => '#'.with_fg(Color::Green).to_string(),
+            false => '.'.with_fg(Color::BrightBlack).to_string(),
+        };
+        inputs.push_str(&cell);
+
+        let solution = solutions.get(year, day).ok();
+        // Without an input directory there are no answers, but the implemented parts still show
+        let answers = Answers::load(year, day).unwrap_or_default();
+        for (part, row) in Part::ALL.into_iter().zip(parts.iter_mut()) {
+            let implemented = solution.is_some_and(|s| s.part(part).is_some());
+            let solved = answers.is_solved(part);
+            stars += solved as u32;
+
+            let cell = match (implemented, solved) {
+                (true, true) => '*'.with_fg(Color::Yellow).to_string(),
+                (false, true) => '*'.with_fg(Color::White).to_string(),
+                (true, false) => '+'.with_fg(Color::White).to_string(),
+                (false, false) => '.'.with_fg(Color::BrightBlack).to_string(),
+            };
+            row.push_str(&cell);
+        }
+    }
+
+    println!("{year:<4} {:5} {inputs}", "input");
+    for (part, row) in Part::ALL.into_iter().zip(parts) {
+        println!("{:4} {:5} {row}", "", part.to_string());
+    }
+    println!("{:10} {stars} {}", "", "stars".with_fg(Color::BrightBlack));
+    println!();
+}
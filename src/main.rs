@@ -1,7 +1,20 @@
+mod answers;
+mod bench;
 mod get;
+mod history;
+mod http;
+mod input;
+mod leaderboard;
+mod list;
+mod new;
+mod profile;
+mod read;
+mod report;
 mod solution;
 mod solve;
+mod submit;
 mod util;
+mod watch;
 mod y2025;
 
 fn main() -> anyhow::Result<()> {
@@ -9,9 +22,20 @@ fn main() -> anyhow::Result<()> {
     trace();
     let cli: Cli = clap::Parser::parse();
 
+    if let Some(profile) = cli.profile.as_deref() {
+        crate::profile::select(profile)?;
+    }
+
     match cli.command {
+        Command::Bench(bench) => crate::bench::run_command(bench),
         Command::Get(get) => crate::get::run_command(get),
+        Command::Leaderboard(leaderboard) => crate::leaderboard::run_command(leaderboard),
+        Command::List(list) => crate::list::run_command(list),
+        Command::New(new) => crate::new::run_command(new),
+        Command::Read(read) => crate::read::run_command(read),
         Command::Solve(solve) => crate::solve::run_command(solve),
+        Command::Submit(submit) => crate::submit::run_command(submit),
+        Command::Watch(watch) => crate::watch::run_command(watch),
     }
 }
 
@@ -25,12 +49,22 @@ fn trace() {
 
 #[derive(clap::Parser)]
 struct Cli {
+    #[arg(long, global = true, env = "ADVENT_PROFILE")]
+    /// Account profile providing the session cookie and input directory
+    profile: Option<String>,
     #[command(subcommand)]
     command: Command,
 }
 
 #[derive(clap::Subcommand)]
 enum Command {
+    Bench(crate::bench::BenchCli),
     Get(crate::get::GetCli),
+    Leaderboard(crate::leaderboard::LeaderboardCli),
+    List(crate::list::ListCli),
+    New(crate::new::NewCli),
+    Read(crate::read::ReadCli),
     Solve(crate::solve::SolveCli),
+    Submit(crate::submit::SubmitCli),
+    Watch(crate::watch::WatchCli),
 }
@@ -0,0 +1,160 @@
+use std::path::{Path, PathBuf};
+
+const SOURCE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src");
+
+#[derive(clap::Args)]
+/// Generate the module of a new day and register it
+pub struct NewCli {
+    #[arg()]
+    year: u32,
+    #[arg()]
+    day: u32,
+}
+
+pub fn run_command(cli: NewCli) -> anyhow::Result<()> {
+    let (year, day) = (cli.year, cli.day);
+    if year < 2015 || !(1..=crate::get::days_in_event(year)).contains(&day) {
+        anyhow::bail!("there is no puzzle {year}-{day}");
+    }
+
+    let source_dir = Path::new(SOURCE_DIR);
+    let year_dir = source_dir.join(format!("y{year}"));
+    let day_path = year_dir.join(format!("d{day:02}.rs"));
+    if day_path.exists() {
+        anyhow::bail!("{} already exists", day_path.display());
+    }
+
+    std::fs::create_dir_all(&year_dir)?;
+    let contents = DAY_TEMPLATE
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string());
+    write(&day_path, &contents)?;
+
+    // The day registers itself, but its module still has to be declared
+    let mod_path = year_dir.join("mod.rs");
+    let line = format!("mod d{day:02};");
+    if mod_path.exists() {
+        edit(&mod_path, |contents| insert_line(contents, &line, "mod d"))?;
+    } else {
+        write(&mod_path, &format!("{line}\n"))?;
+
+        let main_path = source_dir.join("main.rs");
+        let line = format!("mod y{year};");
+        edit(&main_path, |contents| insert_line(contents, &line, "mod "))?;
+    }
+
+    Ok(())
+}
+
+fn write(path: &Path, contents: &str) -> anyhow::Result<()> {
+    std::fs::write(path, contents)?;
+    println!("created {}", relative(path).display());
+    Ok(())
+}
+
+fn edit(path: &Path, f: impl FnOnce(&str) -> anyhow::Result<String>) -> anyhow::Result<()> {
+    let contents = std::fs::read_to_string(path)?;
+    let contents = f(&contents)?;
+    std::fs::write(path, contents)?;
+    println!("updated {}", relative(path).display());
+    Ok(())
+}
+
+fn relative(path: &Path) -> PathBuf {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    path.strip_prefix(root).unwrap_or(path).to_path_buf()
+}
+
+/// Inserts a line into the sorted run of lines starting with `prefix`, with their indentation
+fn insert_line(contents: &str, line: &str, prefix: &str) -> anyhow::Result<String> {
+    let lines: Vec<&str> = contents.lines().collect();
+    let matching: Vec<usize> = (0..lines.len())
+        .filter(|&index| lines[index].trim_start().starts_with(prefix))
+        .collect();
+
+    let (Some(&first), Some(&last)) = (matching.first(), matching.last()) else {
+        anyhow::bail!("no line starting with {prefix:?} to insert {line:?} next to");
+    };
+
+    let indent = &lines[first][..lines[first].len() - lines[first].trim_start().len()];
+    let position = matching
+        .iter()
+        .copied()
+        .find(|&index| lines[index].trim_start() > line)
+        .unwrap_or(last + 1);
+
+    let mut result = String::with_capacity(contents.len() + indent.len() + line.len() + 1);
+    for (index, existing) in lines.iter().enumerate() {
+        if index == position {
+            result.push_str(indent);
+            result.push_str(line);
+            result.push('\n');
+        }
+        result.push_str(existing);
+        result.push('\n');
+    }
+    if position == lines.len() {
+        result.push_str(indent);
+        result.push_str(line);
+        result.push('\n');
+    }
+
+    Ok(result)
+}
+
+const DAY_TEMPLATE: &str = r#"use crate::solution::Solution;
+
+crate::solution::register!({year}, {day});
+
+pub fn solution() -> Solution {
+    Solution::parsed(parse)
+        .with_a(|lines| a(lines))
+        .with_example(EXAMPLE, None, None)
+        .into()
+}
+
+const EXAMPLE: &str = "";
+
+fn parse(input: &str) -> anyhow::Result<Vec<String>> {
+    let lines = input.lines().map(str::to_string).collect();
+    Ok(lines)
+}
+
+fn a(_lines: &[String]) -> anyhow::Result<u64> {
+    todo!()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::EXAMPLE;
+
+    #[rstest]
+    #[case(EXAMPLE, 0)]
+    #[ignore = "the example and its answer are not filled in yet"]
+    fn test_a(#[case] input: &str, #[case] expected: u64) {
+        let lines = super::parse(input).unwrap();
+        let result = super::a(&lines).unwrap();
+        assert_eq!(result, expected);
+    }
+}
+"#;
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    const MOD: &str = "mod d01;
+mod d09;
+";
+
+    #[rstest]
+    #[case("mod d05;", "mod d01;\nmod d05;\nmod d09;\n")]
+    #[case("mod d10;", "mod d01;\nmod d09;\nmod d10;\n")]
+    #[case("mod d00;", "mod d00;\nmod d01;\nmod d09;\n")]
+    fn test_insert_line(#[case] line: &str, #[case] expected: &str) {
+        let result = super::insert_line(MOD, line, "mod d").unwrap();
+        assert_eq!(result, expected);
+    }
+}
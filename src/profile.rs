@@ -0,0 +1,120 @@
+//! Named account profiles, each with its own session cookie and input directory
+//!
+//! Profiles are read from the file named by `ADVENT_PROFILES`, or `profiles` in the working
+//! directory, in the following format:
+//!
+//! ```text
+//! [name]
+//! session = 53616c7465645f5f...
+//! input_dir = inputs/name
+//! ```
+//!
+//! Without an `input_dir`, a profile uses its own subdirectory of `ADVENT_INPUT_DIR`.
+
+use std::{env, io, sync::Mutex};
+
+const PROFILES_VAR: &str = "ADVENT_PROFILES";
+const DEFAULT_PROFILES_FILE: &str = "profiles";
+
+static ACTIVE: Mutex<Option<Profile>> = Mutex::new(None);
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Profile {
+    pub name: String,
+    pub session: Option<String>,
+    pub input_dir: Option<String>,
+}
+
+/// The selected profile, if any; settings it leaves out fall back to the environment
+pub fn active() -> Option<Profile> {
+    ACTIVE.lock().unwrap().clone()
+}
+
+pub fn set_active(profile: Option<Profile>) {
+    *ACTIVE.lock().unwrap() = profile;
+}
+
+pub fn select(name: &str) -> Result<(), ProfileError> {
+    let profile = load()?
+        .into_iter()
+        .find(|profile| profile.name == name)
+        .ok_or_else(|| ProfileError::Unknown(name.to_string()))?;
+    set_active(Some(profile));
+    Ok(())
+}
+
+pub fn load() -> Result<Vec<Profile>, ProfileError> {
+    let path = env::var(PROFILES_VAR).unwrap_or_else(|_| DEFAULT_PROFILES_FILE.to_string());
+    let contents = match std::fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => return Err(error.into()),
+    };
+
+    parse(&contents)
+}
+
+fn parse(contents: &str) -> Result<Vec<Profile>, ProfileError> {
+    let mut profiles: Vec<Profile> = Vec::new();
+    for (index, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            profiles.push(Profile {
+                name: name.trim().to_string(),
+                ..Default::default()
+            });
+            continue;
+        }
+
+        let invalid = || ProfileError::Invalid(index + 1);
+        let profile = profiles.last_mut().ok_or_else(invalid)?;
+        let (key, value) = line.split_once('=').ok_or_else(invalid)?;
+        let value = Some(value.trim().to_string());
+        match key.trim() {
+            "session" => profile.session = value,
+            "input_dir" => profile.input_dir = value,
+            _ => return Err(invalid()),
+        }
+    }
+
+    Ok(profiles)
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ProfileError {
+    #[error("unknown profile {0:?}")]
+    Unknown(String),
+    #[error("invalid profiles file at line {0}")]
+    Invalid(usize),
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Profile;
+
+    #[test]
+    fn test_parse() {
+        let contents =
+            "# team\n[alice]\nsession = abc\ninput_dir = inputs/alice\n\n[bob]\nsession=def\n";
+        let result = super::parse(contents).unwrap();
+        let expected = vec![
+            Profile {
+                name: "alice".to_string(),
+                session: Some("abc".to_string()),
+                input_dir: Some("inputs/alice".to_string()),
+            },
+            Profile {
+                name: "bob".to_string(),
+                session: Some("def".to_string()),
+                input_dir: None,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+}
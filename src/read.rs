@@ -0,0 +1,212 @@
+use crate::{
+    answers::Answers,
+    get::ADVENT_OF_CODE_URI,
+    solution::Part,
+    util::{
+        html,
+        style::{Color, ToStyled as _},
+    },
+};
+
+#[derive(clap::Args)]
+/// Read the puzzle statement in the terminal
+pub struct ReadCli {
+    #[arg(long)]
+    /// Fetch the puzzle page even if it is cached
+    refresh: bool,
+    #[arg()]
+    year: u32,
+    #[arg()]
+    day: u32,
+}
+
+pub fn run_command(cli: ReadCli) -> anyhow::Result<()> {
+    let mut page = crate::get::get_puzzle(cli.year, cli.day, cli.refresh)?;
+
+    // A cached page fetched before part A was solved does not include part B
+    if !cli.refresh && articles(&page).count() < 2 {
+        let answers = Answers::load(cli.year, cli.day)?;
+        if answers.is_solved(Part::A) {
+            page = crate::get::fetch_puzzle(cli.year, cli.day)?;
+        }
+    }
+
+    print!("{}", render(&page));
+    Ok(())
+}
+
+fn articles(page: &str) -> impl Iterator<Item = &str> {
+    html::elements(page, "<article", "</article>").map(|article| {
+        article
+            .split_once('>')
+            .map_or(article, |(_, contents)| contents)
+    })
+}
+
+/// Renders the puzzle articles of a page as styled terminal text
+pub fn render(page: &str) -> String {
+    let mut renderer = Renderer::default();
+    for article in articles(page) {
+        renderer.render(article);
+    }
+    renderer.finish()
+}
+
+#[derive(Default)]
+struct Renderer {
+    output: String,
+    colors: Vec<Color>,
+    links: Vec<String>,
+    pre: bool,
+    pending_newline: bool,
+}
+
+impl Renderer {
+    fn render(&mut self, html: &str) {
+        let mut rest = html;
+        while let Some((text, after)) = rest.split_once('<') {
+            self.text(text);
+            let (tag, after) = after.split_once('>').unwrap_or((after, ""));
+            self.tag(tag);
+            rest = after;
+        }
+        self.text(rest);
+    }
+
+    fn text(&mut self, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+
+        let text = html::decode_entities(text);
+        let text = if self.pre {
+            // Defer line breaks so that the block does not end with an indented empty line
+            let mut indented = String::new();
+            if std::mem::take(&mut self.pending_newline) {
+                indented.push_str("\n    ");
+            }
+            let trimmed = text.strip_suffix('\n');
+            self.pending_newline = trimmed.is_some();
+            indented.push_str(&trimmed.unwrap_or(&text).replace('\n', "\n    "));
+            indented
+        } else if self.output.is_empty() || self.output.ends_with('\n') {
+            text.trim_start().replace('\n', " ")
+        } else {
+            text.replace('\n', " ")
+        };
+
+        if text.is_empty() {
+            return;
+        }
+
+        let color = self.colors.last().copied().unwrap_or_default();
+        if color == Color::Default {
+            self.output.push_str(&text);
+        } else {
+            self.output.push_str(&format!("{}", text.with_fg(color)));
+        }
+    }
+
+    fn tag(&mut self, tag: &str) {
+        let (closing, tag) = match tag.strip_prefix('/') {
+            Some(tag) => (true, tag),
+            None => (false, tag),
+        };
+        let name = tag.split_whitespace().next().unwrap_or_default();
+
+        match (name, closing) {
+            ("h2", false) => self.colors.push(Color::BrightWhite),
+            ("h2", true) => {
+                self.colors.pop();
+                self.output.push_str("\n\n");
+            }
+            ("p", true) => self.output.push_str("\n\n"),
+            ("ul", true) => self.output.push('\n'),
+            ("em", false) => self.colors.push(Color::BrightYellow),
+            ("code", false) if !self.pre => self.colors.push(Color::Green),
+            // Emphasis also appears inside pre blocks, where code does not push a colour
+            ("em", true) => {
+                self.colors.pop();
+            }
+            ("code", true) if !self.pre => {
+                self.colors.pop();
+            }
+            ("pre", false) => {
+                self.pre = true;
+                self.colors.push(Color::Green);
+                self.output.push_str("    ");
+            }
+            ("pre", true) => {
+                self.pre = false;
+                self.pending_newline = false;
+                self.colors.pop();
+                self.output.push_str("\n\n");
+            }
+            ("a", false) => {
+                let href = attribute(tag, "href").unwrap_or_default();
+                let href = if href.starts_with('/') {
+                    format!("{ADVENT_OF_CODE_URI}{href}")
+                } else {
+                    href.to_string()
+                };
+                self.links.push(href);
+                self.colors.push(Color::Cyan);
+            }
+            ("a", true) => {
+                self.colors.pop();
+                self.text(&format!("[{}]", self.links.len()));
+            }
+            ("li", false) => self.output.push_str("  - "),
+            ("li", true) => self.output.push('\n'),
+            _ => {}
+        }
+    }
+
+    fn finish(mut self) -> String {
+        for (index, link) in self.links.iter().enumerate() {
+            let footnote = format!("[{}] {link}\n", index + 1);
+            self.output.push_str(&footnote);
+        }
+        self.output
+    }
+}
+
+fn attribute<'t>(tag: &'t str, name: &str) -> Option<&'t str> {
+    let (_, rest) = tag.split_once(&format!("{name}=\""))?;
+    let (value, _) = rest.split_once('"')?;
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::util::style::{Color, ToStyled as _};
+
+    fn styled(text: &str, color: Color) -> String {
+        format!("{}", text.with_fg(color))
+    }
+
+    #[rstest]
+    #[case("<article><p>Plain &amp; simple.</p></article>", "Plain & simple.\n\n".to_string())]
+    #[case(
+        "<article><p>An <em>emphasised</em> <code>x</code>.</p></article>",
+        format!("An {} {}.\n\n", styled("emphasised", Color::BrightYellow), styled("x", Color::Green))
+    )]
+    #[case(
+        "<article><pre><code>1 <em>2</em> 3\n</code></pre><p>Done.</p></article>",
+        format!(
+            "    {}{}{}\n\nDone.\n\n",
+            styled("1 ", Color::Green),
+            styled("2", Color::BrightYellow),
+            styled(" 3", Color::Green)
+        )
+    )]
+    #[case(
+        "<article><p>See <a href=\"/2025/about\">here</a>.</p></article>",
+        format!("See {}[1].\n\n[1] https://adventofcode.com/2025/about\n", styled("here", Color::Cyan))
+    )]
+    fn test_render(#[case] page: &str, #[case] expected: String) {
+        assert_eq!(super::render(page), expected);
+    }
+}
@@ -0,0 +1,306 @@
+use std::{borrow::Cow, time::Duration};
+
+use crate::{
+    answers::{Answers, Check},
+    history::History,
+    solution::{ParseResult, Part, PartResult, RunOptions, SolutionErrors},
+};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    /// Human readable text
+    #[default]
+    Text,
+    /// One JSON object per line
+    Json,
+    /// Tab separated values with a header line
+    Tsv,
+}
+
+/// Reports part results as they come in and tallies errors
+pub struct Report<'o> {
+    options: &'o RunOptions,
+    history: History,
+    timings: Vec<(u32, u32, Part, Duration)>,
+    error_count: u32,
+}
+
+impl<'o> Report<'o> {
+    pub fn new(options: &'o RunOptions) -> anyhow::Result<Self> {
+        if options.format == Format::Tsv {
+            println!("year\tday\tpart\toutput\tmultiline\tduration_ns\terror");
+        }
+
+        let history = match options.compare {
+            Some(_) => History::load()?,
+            None => History::default(),
+        };
+
+        Ok(Self {
+            options,
+            history,
+            timings: Vec::new(),
+            error_count: 0,
+        })
+    }
+
+    /// Reports the result of a part, run on the real input or on one of the examples
+    pub fn add(
+        &mut self,
+        year: u32,
+        day: u32,
+        part: Part,
+        example: Option<usize>,
+        result: PartResult,
+        answers: &Answers,
+    ) {
+        let check = match &result {
+            Ok((output, _)) => answers.check(part, &format!("{output}")),
+            Err(_) => Check::Unknown,
+        };
+        self.error_count += (result.is_err() || matches!(check, Check::Incorrect(_))) as u32;
+
+        // Only the real input with the default parameters has a timing history, and concurrent
+        // runs compete for the CPU
+        if let (Ok((_, time)), None, None) = (&result, example, &self.options.input)
+            && self.options.params.is_empty()
+            && self.options.jobs <= 1
+        {
+            self.timings.push((year, day, part, *time));
+        }
+
+        if self.options.format != Format::Text {
+            let record = PartRecord::new(year, day, part, example, &result);
+            record.print(self.options.format);
+            return;
+        }
+
+        match example {
+            Some(example) => print!("{year}-{day} {part} (example {example}):"),
+            None => print!("{year}-{day} {part}:"),
+        }
+        print_solution_result(&result, check);
+
+        let (Ok((output, time)), None) = (&result, example) else {
+            return;
+        };
+
+        if let Some(threshold) = self.options.compare {
+            let baseline = self.history.baseline(year, day, part);
+            crate::history::print_comparison(*time, baseline, threshold);
+        }
+
+        if self.options.submit {
+            use crate::{answers::Refusal, submit::SubmitError};
+
+            let submitted = crate::submit::submit_output(year, day, part, output.as_ref());
+            // A part that was already accepted has nothing left to submit
+            let failed = !matches!(
+                submitted,
+                Ok(_) | Err(SubmitError::Refused(Refusal::AlreadyCorrect))
+            );
+            self.error_count += failed as u32;
+            crate::submit::print_submit_result(&submitted);
+        }
+    }
+
+    /// Reports the parse step of a solution that has one
+    pub fn add_parse(&mut self, year: u32, day: u32, example: Option<usize>, result: ParseResult) {
+        use crate::util::style::{Color, ToStyled as _};
+
+        self.error_count += result.is_err() as u32;
+
+        if self.options.format != Format::Text {
+            let record = PartRecord::parse(year, day, example, &result);
+            record.print(self.options.format);
+            return;
+        }
+
+        match example {
+            Some(example) => print!("{year}-{day} parse (example {example}):"),
+            None => print!("{year}-{day} parse:"),
+        }
+        match result {
+            Ok(time) => println!(" {time:?}"),
+            Err(error) => {
+                let error = format!("{error}");
+                println!(" {}", error.with_fg(Color::Red));
+            }
+        }
+    }
+
+    /// Reports a day that was not run
+    pub fn skip(&self, year: u32, day: u32, reason: &str) {
+        use crate::util::style::{Color, ToStyled as _};
+
+        if self.options.format == Format::Text {
+            println!("{year}-{day}: {}", reason.with_fg(Color::BrightBlack));
+        }
+    }
+
+    pub fn finish(self) -> anyhow::Result<()> {
+        crate::history::append(&self.timings)?;
+
+        if self.error_count == 0 {
+            Ok(())
+        } else {
+            Err(SolutionErrors(self.error_count).into())
+        }
+    }
+}
+
+fn print_solution_result(result: &PartResult, check: Check) {
+    use crate::util::style::{Color, ToStyled as _};
+
+    let marker = match check {
+        Check::Correct => "✓".with_fg(Color::Green),
+        Check::Incorrect(_) => "✗".with_fg(Color::Red),
+        Check::Unknown => "unknown".with_fg(Color::BrightBlack),
+    };
+
+    match result {
+        Ok((output, time)) if output.is_multiline() => {
+            println!(" ({time:?}) {marker}");
+            let formatted = format!("{output}");
+            for line in formatted.lines() {
+                println!("  {line}");
+            }
+        }
+        Ok((output, time)) => {
+            println!(" {output} ({time:?}) {marker}");
+        }
+        Err(error) => {
+            let error = format!("{error}");
+            println!(" {}", error.with_fg(Color::Red));
+        }
+    }
+}
+
+#[derive(serde::Serialize)]
+struct PartRecord {
+    year: u32,
+    day: u32,
+    /// The part, or `parse` for the parse step
+    part: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    example: Option<usize>,
+    output: Option<String>,
+    multiline: bool,
+    duration_ns: Option<u128>,
+    error: Option<String>,
+}
+
+impl PartRecord {
+    fn new(year: u32, day: u32, part: Part, example: Option<usize>, result: &PartResult) -> Self {
+        let (output, multiline, duration_ns, error) = match result {
+            Ok((output, time)) => (
+                Some(format!("{output}")),
+                output.is_multiline(),
+                Some(time.as_nanos()),
+                None,
+            ),
+            Err(error) => (None, false, None, Some(format!("{error}"))),
+        };
+
+        Self {
+            year,
+            day,
+            part: part.to_string(),
+            example,
+            output,
+            multiline,
+            duration_ns,
+            error,
+        }
+    }
+
+    fn parse(year: u32, day: u32, example: Option<usize>, result: &ParseResult) -> Self {
+        let (duration_ns, error) = match result {
+            Ok(time) => (Some(time.as_nanos()), None),
+            Err(error) => (None, Some(format!("{error}"))),
+        };
+
+        Self {
+            year,
+            day,
+            part: "parse".to_string(),
+            example,
+            output: None,
+            multiline: false,
+            duration_ns,
+            error,
+        }
+    }
+
+    fn print(&self, format: Format) {
+        match format {
+            Format::Text => unreachable!("text results are not printed as records"),
+            Format::Json => match serde_json::to_string(self) {
+                Ok(json) => println!("{json}"),
+                Err(error) => tracing::error!(%error, "Unable to serialize result"),
+            },
+            Format::Tsv => println!(
+                "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+                self.year,
+                self.day,
+                self.part,
+                escape_tsv(self.output.as_deref().unwrap_or_default()),
+                self.multiline,
+                self.duration_ns.map(|d| d.to_string()).unwrap_or_default(),
+                escape_tsv(self.error.as_deref().unwrap_or_default()),
+            ),
+        }
+    }
+}
+
+fn escape_tsv(value: &str) -> Cow<'_, str> {
+    if value.contains(['\\', '\t', '\n', '\r']) {
+        let escaped = value
+            .replace('\\', "\\\\")
+            .replace('\t', "\\t")
+            .replace('\n', "\\n")
+            .replace('\r', "\\r");
+        Cow::Owned(escaped)
+    } else {
+        Cow::Borrowed(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use rstest::rstest;
+
+    use super::PartRecord;
+    use crate::solution::{BoxedOutput, Part, PartResult};
+
+    #[rstest]
+    #[case("plain", "plain")]
+    #[case("a\tb", "a\\tb")]
+    #[case("line\nline\r\n", "line\\nline\\r\\n")]
+    #[case("C:\\path\\n", "C:\\\\path\\\\n")]
+    fn test_escape_tsv(#[case] value: &str, #[case] expected: &str) {
+        assert_eq!(super::escape_tsv(value), expected);
+    }
+
+    #[rstest]
+    #[case(
+        None,
+        Err(anyhow::anyhow!("invalid input")),
+        r#"{"year":2025,"day":8,"part":"A","output":null,"multiline":false,"duration_ns":null,"error":"invalid input"}"#
+    )]
+    #[case(
+        Some(1),
+        Ok((Box::new(40u64) as BoxedOutput, Duration::from_nanos(1500))),
+        r#"{"year":2025,"day":8,"part":"A","example":1,"output":"40","multiline":false,"duration_ns":1500,"error":null}"#
+    )]
+    fn test_json_record(
+        #[case] example: Option<usize>,
+        #[case] result: PartResult,
+        #[case] expected: &str,
+    ) {
+        let record = PartRecord::new(2025, 8, Part::A, example, &result);
+        assert_eq!(serde_json::to_string(&record).unwrap(), expected);
+    }
+}
@@ -1,11 +1,50 @@
 use std::{
+    any::Any,
+    borrow::Cow,
     collections::BTreeMap,
+    fmt::Display,
+    marker::PhantomData,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::Arc,
     time::{Duration, Instant},
 };
 
-use crate::util::{output::Output, vecset::SortedVecSet};
+use crate::{
+    answers::Answers,
+    input::{InputError, Normalize},
+    report::{Format, Report},
+    submit::Verdict,
+    util::{output::Output, vecset::SortedVecSet},
+};
+
+pub type RunEntry<'a> = ((u32, u32), &'a Solution);
+
+/// A solution announced by its day module, collected by [`Solutions::new`]
+#[derive(Debug, Clone, Copy)]
+pub struct Registration {
+    pub year: u32,
+    pub day: u32,
+    pub module: &'static str,
+    pub solution: fn() -> Solution,
+}
 
-type RunEntry<'a> = ((u32, u32), &'a Solution);
+inventory::collect!(Registration);
+
+/// Registers the `solution` function of the calling module as the solution of a day
+macro_rules! register {
+    ($year:literal, $day:literal) => {
+        ::inventory::submit! {
+            $crate::solution::Registration {
+                year: $year,
+                day: $day,
+                module: ::std::module_path!(),
+                solution: self::solution,
+            }
+        }
+    };
+}
+pub(crate) use register;
 
 pub struct Solutions {
     entries: BTreeMap<(u32, u32), Solution>,
@@ -19,9 +58,24 @@ impl Default for Solutions {
 }
 
 impl Solutions {
+    /// Collects every solution announced with [`register!`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if two modules register a solution for the same day.
     pub fn new() -> Self {
         let mut solutions = Self::empty();
-        crate::y2025::register(&mut solutions);
+        let mut modules = BTreeMap::new();
+        for registration in inventory::iter::<Registration> {
+            let Registration { year, day, .. } = *registration;
+            if let Some(previous) = modules.insert((year, day), registration.module) {
+                panic!(
+                    "{year}-{day} is registered by both {previous} and {}",
+                    registration.module
+                );
+            }
+            solutions.register(year, day, (registration.solution)());
+        }
         solutions
     }
 
@@ -41,151 +95,872 @@ impl Solutions {
         days.insert(day);
     }
 
-    pub fn run_all(&self) -> anyhow::Result<()> {
-        let entries = self.entries.iter().map(|(k, v)| (*k, v));
-        Self::run_entries(entries)
+    pub fn get(&self, year: u32, day: u32) -> anyhow::Result<&Solution> {
+        self.entries
+            .get(&(year, day))
+            .ok_or_else(|| anyhow::anyhow!("No solution registered for year {}, day {}", year, day))
+    }
+
+    /// Years with registered solutions, with their registered days
+    pub fn years(&self) -> impl Iterator<Item = (u32, &[u32])> {
+        self.years
+            .iter()
+            .map(|(year, days)| (*year, days.as_slice()))
     }
 
-    pub fn run_year(&self, year: u32) -> anyhow::Result<()> {
+    pub fn all(&self) -> impl Iterator<Item = RunEntry<'_>> {
+        self.entries.iter().map(|(k, v)| (*k, v))
+    }
+
+    pub fn year(&self, year: u32) -> anyhow::Result<impl Iterator<Item = RunEntry<'_>>> {
         let days = self
             .years
             .get(&year)
             .ok_or_else(|| anyhow::anyhow!("No solutions registed for year {}", year))?
             .as_slice();
 
-        let entries = days.iter().flat_map(|day| {
+        let entries = days.iter().flat_map(move |day| {
             let k = (year, *day);
             self.entries.get(&k).map(|v| (k, v))
         });
 
-        Self::run_entries(entries)
+        Ok(entries)
     }
 
-    pub fn run_day(&self, year: u32, day: u32) -> anyhow::Result<()> {
-        let key = (year, day);
-        let solution = self.entries.get(&key).ok_or_else(|| {
-            anyhow::anyhow!("No solution registered for year {}, day {}", year, day)
-        })?;
+    /// Runs the entries, reporting their results as they come in
+    pub fn run<'a>(
+        entries: impl IntoIterator<Item = RunEntry<'a>>,
+        options: &RunOptions,
+    ) -> anyhow::Result<()> {
+        let mut report = Report::new(options)?;
+
+        if options.example {
+            for ((year, day), solution) in entries {
+                Self::run_examples(year, day, solution, options, &mut report)?;
+            }
+        } else if options.jobs > 1 {
+            let entries: Vec<_> = entries.into_iter().collect();
+            Self::run_entries_parallel(&entries, options, |index, parse, results| {
+                let ((year, day), _) = entries[index];
+                let answers = options.answers(year, day)?;
+                if let Some(parse) = parse {
+                    report.add_parse(year, day, None, parse);
+                }
+                for (part, result) in results {
+                    report.add(year, day, part, None, result, &answers);
+                }
+                Ok(())
+            })?;
+        } else {
+            for ((year, day), solution) in entries {
+                let input = options.input(year, day)?;
+                let params = solution.params(&options.params)?;
+                let answers = options.answers(year, day)?;
+                let (parse, results) =
+                    solution.run(&input, &params, options.parts(), options.timeout);
+                if let Some(parse) = parse {
+                    report.add_parse(year, day, None, parse);
+                }
+                for (part, result) in results {
+                    report.add(year, day, part, None, result, &answers);
+                }
+            }
+        }
 
-        Self::run_entries([(key, solution)])
+        report.finish()
     }
 
-    fn run_entries<'a>(entries: impl IntoIterator<Item = RunEntry<'a>>) -> anyhow::Result<()> {
-        let mut error_count = 0;
+    fn run_examples(
+        year: u32,
+        day: u32,
+        solution: &Solution,
+        options: &RunOptions,
+        report: &mut Report,
+    ) -> anyhow::Result<()> {
+        if solution.examples.is_empty() {
+            // Fall back to the examples extracted from the puzzle page, without expected answers
+            let examples = match crate::get::read_examples(year, day) {
+                Ok(examples) if !examples.is_empty() => examples,
+                _ => {
+                    report.skip(year, day, "no examples");
+                    return Ok(());
+                }
+            };
 
-        for ((year, day), solution) in entries {
-            let input = crate::get::get_input(year, day)?;
-            if let Err(errors) = solution.run(year, day, &input) {
-                error_count += errors.0;
+            let params = solution.params(&options.params)?;
+            let answers = Answers::default();
+            for (index, example) in examples.iter().enumerate() {
+                let (parse, results) =
+                    solution.run(example, &params, options.parts(), options.timeout);
+                if let Some(parse) = parse {
+                    report.add_parse(year, day, Some(index + 1), parse);
+                }
+                for (part, result) in results {
+                    report.add(year, day, part, Some(index + 1), result, &answers);
+                }
             }
+            return Ok(());
         }
 
-        if error_count == 0 {
+        for (index, example) in solution.examples.iter().enumerate() {
+            // The example's parameters can still be overridden from the command line
+            let overrides: Vec<_> = example
+                .params
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .chain(options.params.iter().cloned())
+                .collect();
+            let params = solution.params(&overrides)?;
+            let answers = example.answers();
+            let parts: Vec<_> = example
+                .parts()
+                .into_iter()
+                .filter(|part| options.parts().contains(part))
+                .collect();
+            let (parse, results) = solution.run(example.input, &params, &parts, options.timeout);
+            if let Some(parse) = parse {
+                report.add_parse(year, day, Some(index + 1), parse);
+            }
+            for (part, result) in results {
+                report.add(year, day, part, Some(index + 1), result, &answers);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Runs days on a pool of worker threads, passing results to `report` in entry order
+    fn run_entries_parallel(
+        entries: &[RunEntry<'_>],
+        options: &RunOptions,
+        mut report: impl FnMut(
+            usize,
+            Option<ParseResult>,
+            Vec<(Part, PartResult)>,
+        ) -> anyhow::Result<()>,
+    ) -> anyhow::Result<()> {
+        use std::sync::{
+            atomic::{AtomicUsize, Ordering},
+            mpsc,
+        };
+
+        // Inputs are read up front so that the AoC server is not hit concurrently
+        let inputs = entries
+            .iter()
+            .map(|((year, day), solution)| {
+                let input = options.input(*year, *day)?;
+                let params = solution.params(&options.params)?;
+                Ok((input, params))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let next = AtomicUsize::new(0);
+        let (sender, receiver) = mpsc::channel();
+
+        std::thread::scope(|scope| {
+            let timeout = options.timeout;
+            for _ in 0..options.jobs.min(entries.len()) {
+                let sender = sender.clone();
+                let (next, inputs) = (&next, &inputs);
+                scope.spawn(move || {
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some((_, solution)) = entries.get(index) else {
+                            break;
+                        };
+
+                        let (input, params) = &inputs[index];
+                        let (parse, results) =
+                            solution.run(input, params, options.parts(), timeout);
+                        let results: Vec<_> = results.collect();
+                        if sender.send((index, parse, results)).is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+            drop(sender);
+
+            let mut pending = BTreeMap::new();
+            let mut current = 0;
+            for (index, parse, results) in receiver {
+                pending.insert(index, (parse, results));
+                while let Some((parse, results)) = pending.remove(&current) {
+                    report(current, parse, results)?;
+                    current += 1;
+                }
+            }
+
             Ok(())
-        } else {
-            Err(SolutionErrors(error_count).into())
+        })
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct RunOptions {
+    /// Submit each successful result to the AoC server
+    pub submit: bool,
+    /// Compare timings against the history, highlighting changes above this percentage
+    pub compare: Option<f64>,
+    /// Number of days to run concurrently; values below 2 run sequentially
+    pub jobs: usize,
+    /// Wall-clock time limit for each part
+    pub timeout: Option<Duration>,
+    pub format: Format,
+    /// Run the registered examples instead of the real input
+    pub example: bool,
+    /// File to read instead of the real input, `-` for stdin
+    pub input: Option<PathBuf>,
+    /// Run only this part
+    pub part: Option<Part>,
+    /// Overrides of the solutions' parameters
+    pub params: Vec<(String, String)>,
+}
+
+impl RunOptions {
+    pub fn parts(&self) -> &'static [Part] {
+        match self.part {
+            Some(Part::A) => &[Part::A],
+            Some(Part::B) => &[Part::B],
+            None => &Part::ALL,
+        }
+    }
+
+    fn input(&self, year: u32, day: u32) -> anyhow::Result<String> {
+        let input = match self.input.as_deref() {
+            None => crate::get::get_input(year, day)?,
+            Some(path) if path == Path::new("-") => std::io::read_to_string(std::io::stdin())?,
+            Some(path) => std::fs::read_to_string(path)
+                .map_err(|error| anyhow::anyhow!("{}: {error}", path.display()))?,
+        };
+        Ok(input)
+    }
+
+    /// The answer ledger, which only applies to the real input
+    fn answers(&self, year: u32, day: u32) -> anyhow::Result<Answers> {
+        match self.input {
+            Some(_) => Ok(Answers::default()),
+            None => Ok(Answers::load(year, day)?),
         }
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct Example {
+    pub input: &'static str,
+    pub expected: [Option<&'static str>; 2],
+    /// Parameter values the expected answers depend on
+    pub params: &'static [(&'static str, &'static str)],
+}
+
+impl Example {
+    pub fn expected(&self, part: Part) -> Option<&'static str> {
+        match part {
+            Part::A => self.expected[0],
+            Part::B => self.expected[1],
+        }
+    }
+
+    /// Parts with an expected answer
+    pub fn parts(&self) -> Vec<Part> {
+        Part::ALL
+            .into_iter()
+            .filter(|part| self.expected(*part).is_some())
+            .collect()
+    }
+
+    /// The expected answers, as a ledger to check results against
+    pub fn answers(&self) -> Answers {
+        let mut answers = Answers::default();
+        for part in self.parts() {
+            if let Some(expected) = self.expected(part) {
+                answers.record(part, expected, Verdict::Correct);
+            }
+        }
+        answers
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl Part {
+    pub const ALL: [Self; 2] = [Self::A, Self::B];
+
+    /// The puzzle level expected by the AoC server
+    pub fn level(&self) -> u32 {
+        match self {
+            Self::A => 1,
+            Self::B => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::A => f.write_str("A"),
+            Self::B => f.write_str("B"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = ParsePartError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "a" | "A" | "1" => Ok(Self::A),
+            "b" | "B" | "2" => Ok(Self::B),
+            _ => Err(ParsePartError),
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+#[error("must be one of \"a\", \"b\", \"1\" or \"2\"")]
+pub struct ParsePartError;
+
 #[derive(Default)]
 pub struct Solution {
-    a: Option<BoxedFn>,
-    b: Option<BoxedFn>,
+    parse: Option<Arc<ParseFn>>,
+    a: Option<SharedFn>,
+    b: Option<SharedFn>,
+    examples: Vec<Example>,
+    input: Normalize,
+    params: Vec<(&'static str, &'static str)>,
 }
 
 impl Solution {
     pub fn new() -> Self {
-        Self { a: None, b: None }
+        Self {
+            parse: None,
+            a: None,
+            b: None,
+            examples: Vec::new(),
+            input: Normalize::default(),
+            params: Vec::new(),
+        }
+    }
+
+    /// Starts a solution whose parts receive the result of a single, separately timed parse
+    pub fn parsed<F, P>(parse: F) -> ParsedSolution<P>
+    where
+        F: Fn(&str) -> anyhow::Result<P> + Send + Sync + 'static,
+        P: Send + Sync + 'static,
+    {
+        let parse = move |input: &str| {
+            let start = Instant::now();
+            let parsed = (parse)(input)?;
+            let time = start.elapsed();
+            let shared: ParsedInput = Arc::new(parsed);
+            Ok((shared, time))
+        };
+
+        ParsedSolution {
+            solution: Self {
+                parse: Some(Arc::new(parse)),
+                ..Self::new()
+            },
+            parsed: PhantomData,
+        }
     }
 
     pub fn with_a<A, O>(self, a: A) -> Self
     where
-        A: Fn(&str) -> anyhow::Result<O> + 'static,
-        O: Output + 'static,
+        A: Fn(&str) -> anyhow::Result<O> + Send + Sync + 'static,
+        O: Output + Send + 'static,
+    {
+        self.with_a_params(move |input, _| a(input))
+    }
+
+    pub fn with_b<B, O>(self, b: B) -> Self
+    where
+        B: Fn(&str) -> anyhow::Result<O> + Send + Sync + 'static,
+        O: Output + Send + 'static,
+    {
+        self.with_b_params(move |input, _| b(input))
+    }
+
+    /// Registers part A as a function of the input and the solution's parameters
+    pub fn with_a_params<A, O>(self, a: A) -> Self
+    where
+        A: Fn(&str, &Params) -> anyhow::Result<O> + Send + Sync + 'static,
+        O: Output + Send + 'static,
     {
         Self {
-            a: Some(wrap(a)),
+            a: Some(wrap_inner(move |input, _, params| a(input, params))),
             ..self
         }
     }
 
-    pub fn with_b<B, O>(self, b: B) -> Self
+    /// Registers part B as a function of the input and the solution's parameters
+    pub fn with_b_params<B, O>(self, b: B) -> Self
     where
-        B: Fn(&str) -> anyhow::Result<O> + 'static,
-        O: Output + 'static,
+        B: Fn(&str, &Params) -> anyhow::Result<O> + Send + Sync + 'static,
+        O: Output + Send + 'static,
     {
         Self {
-            b: Some(wrap(b)),
+            b: Some(wrap_inner(move |input, _, params| b(input, params))),
             ..self
         }
     }
 
-    fn run(&self, year: u32, day: u32, input: &str) -> Result<(), SolutionErrors> {
-        let mut error_count = 0;
-        if let Some(a) = self.a.as_deref() {
-            let result = (a)(input);
-            error_count += result.is_err() as u32;
-            print!("{year}-{day} A:");
-            print_solution_result(&result);
+    /// Declares a named parameter with the value used unless a run overrides it
+    pub fn with_param(mut self, name: &'static str, default: &'static str) -> Self {
+        self.params.push((name, default));
+        self
+    }
+
+    /// Registers an example input with its expected answers; parts without an answer are skipped
+    pub fn with_example(
+        self,
+        input: &'static str,
+        a: Option<&'static str>,
+        b: Option<&'static str>,
+    ) -> Self {
+        self.with_example_params(input, &[], a, b)
+    }
+
+    /// Registers an example input whose expected answers depend on parameter values
+    pub fn with_example_params(
+        mut self,
+        input: &'static str,
+        params: &'static [(&'static str, &'static str)],
+        a: Option<&'static str>,
+        b: Option<&'static str>,
+    ) -> Self {
+        self.examples.push(Example {
+            input,
+            expected: [a, b],
+            params,
+        });
+        self
+    }
+
+    /// Replaces the checks and normalisation applied to inputs before they reach the parts
+    pub fn with_input(self, input: Normalize) -> Self {
+        Self { input, ..self }
+    }
+
+    pub fn prepare<'i>(&self, input: &'i str) -> Result<Cow<'i, str>, InputError> {
+        crate::input::normalize(input, self.input)
+    }
+
+    /// The declared parameters, with the given overrides applied
+    pub fn params(&self, overrides: &[(String, String)]) -> anyhow::Result<Params> {
+        let mut params: BTreeMap<_, _> = self
+            .params
+            .iter()
+            .map(|(name, default)| (*name, default.to_string()))
+            .collect();
+
+        for (name, value) in overrides {
+            let Some(param) = params.get_mut(name.as_str()) else {
+                use itertools::Itertools as _;
+
+                let known = self.params.iter().map(|(name, _)| name).join(", ");
+                anyhow::bail!("unknown parameter {name:?}; declared parameters: [{known}]");
+            };
+            *param = value.clone();
+        }
+
+        Ok(Params(params))
+    }
+
+    pub fn parser(&self) -> Option<&ParseFn> {
+        self.parse.as_deref()
+    }
+
+    /// Runs the parse step on a prepared input; without one, the parts only need the input
+    pub fn parse(&self, input: &str) -> anyhow::Result<ParsedInput> {
+        match self.parser() {
+            Some(parse) => parse(input).map(|(parsed, _)| parsed),
+            None => Ok(Arc::new(())),
         }
+    }
+
+    pub fn part(&self, part: Part) -> Option<&InnerFn> {
+        self.shared_part(part).map(|f| f.as_ref())
+    }
 
-        if let Some(b) = self.b.as_deref() {
-            let result = (b)(input);
-            error_count += result.is_err() as u32;
-            print!("{year}-{day} B:");
-            print_solution_result(&result);
+    fn shared_part(&self, part: Part) -> Option<&SharedFn> {
+        match part {
+            Part::A => self.a.as_ref(),
+            Part::B => self.b.as_ref(),
         }
+    }
 
-        if error_count == 0 {
-            Ok(())
-        } else {
-            Err(SolutionErrors(error_count))
+    /// Parses the prepared input, then lazily runs each registered part on it
+    ///
+    /// The parse result is only returned for solutions with a parse step. A bad input fails
+    /// every part without running it, and a failed parse skips the parts.
+    fn run<'a>(
+        &'a self,
+        input: &str,
+        params: &Params,
+        parts: &'a [Part],
+        timeout: Option<Duration>,
+    ) -> (
+        Option<ParseResult>,
+        impl Iterator<Item = (Part, PartResult)> + 'a,
+    ) {
+        let input = self.prepare(input).map(Arc::<str>::from);
+        let (parse, parsed) = match (&input, &self.parse) {
+            (Ok(input), Some(parse)) => {
+                let (parse, input) = (parse.clone(), input.clone());
+                match execute(move || (parse)(&input), timeout) {
+                    Ok((parsed, time)) => (Some(Ok(time)), Some(parsed)),
+                    Err(error) => (Some(Err(error)), None),
+                }
+            }
+            _ => (None, Some(Arc::new(()) as ParsedInput)),
+        };
+
+        let (params, skipped) = (params.clone(), parsed.is_none());
+        let results = parts
+            .iter()
+            .copied()
+            .filter(move |_| !skipped)
+            .filter_map(|part| self.shared_part(part).map(|f| (part, f)))
+            .map(move |(part, f)| match (&input, &parsed) {
+                (Ok(input), Some(parsed)) => {
+                    let (f, input, parsed) = (f.clone(), input.clone(), parsed.clone());
+                    let params = params.clone();
+                    (
+                        part,
+                        execute(move || (f)(&input, &parsed, &params), timeout),
+                    )
+                }
+                (Err(error), _) => (part, Err((*error).into())),
+                (Ok(_), None) => unreachable!("parts are skipped when the parse fails"),
+            });
+
+        (parse, results)
+    }
+}
+
+/// Builder for a solution whose parts share the result of its parse step
+pub struct ParsedSolution<P> {
+    solution: Solution,
+    parsed: PhantomData<fn() -> P>,
+}
+
+impl<P: Send + Sync + 'static> ParsedSolution<P> {
+    pub fn with_a<A, O>(self, a: A) -> Self
+    where
+        A: Fn(&P) -> anyhow::Result<O> + Send + Sync + 'static,
+        O: Output + Send + 'static,
+    {
+        self.with_a_params(move |parsed, _| a(parsed))
+    }
+
+    pub fn with_b<B, O>(self, b: B) -> Self
+    where
+        B: Fn(&P) -> anyhow::Result<O> + Send + Sync + 'static,
+        O: Output + Send + 'static,
+    {
+        self.with_b_params(move |parsed, _| b(parsed))
+    }
+
+    /// Registers part A as a function of the parsed input and the solution's parameters
+    pub fn with_a_params<A, O>(self, a: A) -> Self
+    where
+        A: Fn(&P, &Params) -> anyhow::Result<O> + Send + Sync + 'static,
+        O: Output + Send + 'static,
+    {
+        let a = wrap_inner(move |_, parsed, params| a(downcast(parsed), params));
+        self.map(|solution| Solution {
+            a: Some(a),
+            ..solution
+        })
+    }
+
+    /// Registers part B as a function of the parsed input and the solution's parameters
+    pub fn with_b_params<B, O>(self, b: B) -> Self
+    where
+        B: Fn(&P, &Params) -> anyhow::Result<O> + Send + Sync + 'static,
+        O: Output + Send + 'static,
+    {
+        let b = wrap_inner(move |_, parsed, params| b(downcast(parsed), params));
+        self.map(|solution| Solution {
+            b: Some(b),
+            ..solution
+        })
+    }
+
+    pub fn with_param(self, name: &'static str, default: &'static str) -> Self {
+        self.map(|solution| solution.with_param(name, default))
+    }
+
+    pub fn with_example(
+        self,
+        input: &'static str,
+        a: Option<&'static str>,
+        b: Option<&'static str>,
+    ) -> Self {
+        self.map(|solution| solution.with_example(input, a, b))
+    }
+
+    pub fn with_example_params(
+        self,
+        input: &'static str,
+        params: &'static [(&'static str, &'static str)],
+        a: Option<&'static str>,
+        b: Option<&'static str>,
+    ) -> Self {
+        self.map(|solution| solution.with_example_params(input, params, a, b))
+    }
+
+    fn map(self, f: impl FnOnce(Solution) -> Solution) -> Self {
+        Self {
+            solution: f(self.solution),
+            parsed: PhantomData,
         }
     }
 }
 
+impl<P> From<ParsedSolution<P>> for Solution {
+    fn from(value: ParsedSolution<P>) -> Self {
+        value.solution
+    }
+}
+
 #[derive(thiserror::Error, Debug, Clone, Copy)]
 #[error("{} {} failed", .0, if *.0 > 1 {"solutions"} else {"solution"})]
-struct SolutionErrors(u32);
+pub struct SolutionErrors(pub u32);
 
-fn print_solution_result(result: &anyhow::Result<(BoxedOutput, Duration)>) {
-    use crate::util::style::{Color, ToStyled as _};
+pub type BoxedOutput = Box<dyn Output + Send>;
+pub type PartResult = anyhow::Result<(BoxedOutput, Duration)>;
+pub type InnerFn = dyn Fn(&str, &ParsedInput, &Params) -> PartResult + Send + Sync;
+type SharedFn = Arc<InnerFn>;
 
-    match result {
-        Ok((output, time)) if output.is_multiline() => {
-            println!(" ({time:?})");
-            let formatted = format!("{output}");
-            for line in formatted.lines() {
-                println!("  {line}");
-            }
-        }
-        Ok((output, time)) => {
-            println!(" {output} ({time:?})");
-        }
-        Err(error) => {
-            let error = format!("{error}");
-            println!(" {}", error.with_fg(Color::Red));
-        }
+/// The result of a solution's parse step, shared by its parts
+pub type ParsedInput = Arc<dyn Any + Send + Sync>;
+pub type ParseResult = anyhow::Result<Duration>;
+pub type ParseFn = dyn Fn(&str) -> anyhow::Result<(ParsedInput, Duration)> + Send + Sync;
+
+/// Values of a solution's named parameters for a run
+#[derive(Debug, Default, Clone)]
+pub struct Params(BTreeMap<&'static str, String>);
+
+impl Params {
+    pub fn get<T>(&self, name: &str) -> anyhow::Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let value = self
+            .0
+            .get(name)
+            .ok_or_else(|| anyhow::anyhow!("parameter {name:?} is not declared"))?;
+        value
+            .parse()
+            .map_err(|error| anyhow::anyhow!("invalid parameter {name}={value}: {error}"))
     }
 }
 
-type BoxedOutput = Box<dyn Output>;
-type InnerFn = dyn Fn(&str) -> anyhow::Result<(BoxedOutput, Duration)>;
-type BoxedFn = Box<InnerFn>;
-
-fn wrap<F, O>(f: F) -> BoxedFn
+fn wrap_inner<F, O>(f: F) -> SharedFn
 where
-    F: Fn(&str) -> anyhow::Result<O> + 'static,
-    O: Output + 'static,
+    F: Fn(&str, &ParsedInput, &Params) -> anyhow::Result<O> + Send + Sync + 'static,
+    O: Output + Send + 'static,
 {
-    let closure = move |input: &str| {
+    let closure = move |input: &str, parsed: &ParsedInput, params: &Params| {
         let start = Instant::now();
-        let output = (f)(input)?;
+        let output = (f)(input, parsed, params)?;
         let time = start.elapsed();
-        let boxed: Box<dyn Output> = Box::new(output);
+        let boxed: BoxedOutput = Box::new(output);
         Ok((boxed, time))
     };
 
-    Box::new(closure)
+    Arc::new(closure)
+}
+
+fn downcast<P: 'static>(parsed: &ParsedInput) -> &P {
+    parsed
+        .downcast_ref()
+        .expect("parts receive the result of their own solution's parse step")
+}
+
+/// Runs a parse step or a part, turning panics into errors
+///
+/// With a timeout, it runs on its own thread, which is abandoned if the timeout elapses.
+fn execute<F, T>(f: F, timeout: Option<Duration>) -> anyhow::Result<T>
+where
+    F: FnOnce() -> anyhow::Result<T> + Send + 'static,
+    T: Send + 'static,
+{
+    use std::panic::{AssertUnwindSafe, catch_unwind};
+
+    let Some(timeout) = timeout else {
+        return catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(panicked);
+    };
+
+    let (sender, receiver) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        let result = catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(panicked);
+        let _ = sender.send(result);
+    });
+
+    receiver
+        .recv_timeout(timeout)
+        .unwrap_or_else(|_| Err(anyhow::anyhow!("timed out after {:?}", timeout)))
+}
+
+fn panicked<T>(payload: Box<dyn Any + Send>) -> anyhow::Result<T> {
+    let message = if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.as_str()
+    } else {
+        "unknown panic payload"
+    };
+
+    Err(anyhow::anyhow!("panicked: {}", message))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Part, Registration, RunOptions, Solution, SolutionErrors, Solutions};
+
+    #[test]
+    fn test_registrations() {
+        // Panics on duplicate registrations
+        super::Solutions::new();
+
+        for registration in inventory::iter::<Registration> {
+            let Registration { year, day, .. } = *registration;
+            let expected = format!("::y{year}::d{day:02}");
+            assert!(
+                registration.module.ends_with(&expected),
+                "{} registers {year}-{day}",
+                registration.module
+            );
+        }
+    }
+
+    #[test]
+    fn test_every_day_module_is_registered() {
+        let source_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        for year_dir in std::fs::read_dir(source_dir).unwrap() {
+            let year_dir = year_dir.unwrap().path();
+            let Some(year) = year_dir
+                .file_name()
+                .and_then(|name| name.to_str()?.strip_prefix('y')?.parse::<u32>().ok())
+            else {
+                continue;
+            };
+
+            for file in std::fs::read_dir(&year_dir).unwrap() {
+                let file = file.unwrap().path();
+                let Some(day) = file
+                    .file_stem()
+                    .and_then(|name| name.to_str()?.strip_prefix('d')?.parse::<u32>().ok())
+                else {
+                    continue;
+                };
+
+                let registered = inventory::iter::<Registration>
+                    .into_iter()
+                    .any(|registration| (registration.year, registration.day) == (year, day));
+                assert!(registered, "{} is not registered", file.display());
+            }
+        }
+    }
+
+    #[test]
+    fn test_params_reach_both_parts() {
+        let solution = Solution::new()
+            .with_param("factor", "2")
+            .with_a_params(|input, params| Ok(input.len() * params.get::<usize>("factor")?))
+            .with_b_params(|input, params| Ok(input.len() + params.get::<usize>("factor")?));
+
+        let params = solution
+            .params(&[("factor".to_string(), "10".to_string())])
+            .unwrap();
+        let parsed = solution.parse("abc").unwrap();
+        let results: Vec<String> = [Part::A, Part::B]
+            .into_iter()
+            .map(|part| {
+                let (output, _) = solution.part(part).unwrap()("abc", &parsed, &params).unwrap();
+                output.to_string()
+            })
+            .collect();
+        assert_eq!(results, ["30", "13"]);
+    }
+
+    #[test]
+    fn test_parallel_runs_report_in_entry_order() {
+        let slow = Solution::new()
+            .with_a(|_| {
+                std::thread::sleep(Duration::from_millis(100));
+                Ok(1)
+            })
+            .with_b(|_| -> anyhow::Result<u32> { anyhow::bail!("failed") });
+        let fast = Solution::new()
+            .with_a(|_| Ok(2))
+            .with_b(|_| -> anyhow::Result<u32> { anyhow::bail!("failed") });
+        let entries = [((2025, 1), &slow), ((2025, 2), &fast), ((2025, 3), &fast)];
+
+        // Any file will do, the solutions ignore their input
+        let options = RunOptions {
+            jobs: 3,
+            input: Some(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml").into()),
+            ..Default::default()
+        };
+
+        let mut order = Vec::new();
+        Solutions::run_entries_parallel(&entries, &options, |index, _, results| {
+            let outputs: Vec<_> = results
+                .into_iter()
+                .map(|(part, result)| (part, result.map(|(output, _)| output.to_string()).ok()))
+                .collect();
+            order.push((index, outputs));
+            Ok(())
+        })
+        .unwrap();
+        let expected: Vec<_> = [("1", 0), ("2", 1), ("2", 2)]
+            .into_iter()
+            .map(|(a, index)| (index, vec![(Part::A, Some(a.to_string())), (Part::B, None)]))
+            .collect();
+        assert_eq!(order, expected);
+
+        let error = Solutions::run(entries, &options).unwrap_err();
+        let SolutionErrors(count) = error.downcast().unwrap();
+        assert_eq!(count, 3);
+    }
+
+    #[test]
+    fn test_execute_str_panic() {
+        let result = super::execute(|| -> anyhow::Result<()> { panic!("boom") }, None);
+        assert_eq!(result.unwrap_err().to_string(), "panicked: boom");
+    }
+
+    #[test]
+    fn test_execute_string_panic() {
+        let result = super::execute(
+            || -> anyhow::Result<()> { panic!("{} {}", "formatted", 42) },
+            None,
+        );
+        assert_eq!(result.unwrap_err().to_string(), "panicked: formatted 42");
+    }
+
+    #[test]
+    fn test_execute_timeout() {
+        let timeout = Duration::from_millis(10);
+        let slow = || {
+            std::thread::sleep(Duration::from_millis(500));
+            Ok(())
+        };
+        let result = super::execute(slow, Some(timeout));
+        assert_eq!(result.unwrap_err().to_string(), "timed out after 10ms");
+
+        let result = super::execute(|| Ok(1), Some(timeout));
+        assert_eq!(result.unwrap(), 1);
+    }
 }
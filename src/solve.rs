@@ -1,45 +1,349 @@
-use std::str::FromStr;
+use std::{ops::RangeInclusive, path::PathBuf, str::FromStr, time::Duration};
 
-use crate::solution::Solutions;
+use crate::{
+    report::Format,
+    solution::{Part, RunEntry, RunOptions, SolutionErrors, Solutions},
+    util::vecset::SortedVecSet,
+};
 
 #[derive(clap::Args)]
 /// Run solutions on your input files
 pub struct SolveCli {
     #[arg()]
+    /// "all", "latest", "today", a year or a range of years like 2015..2025
     year: SolveYear,
     #[arg()]
-    day: Option<u32>,
+    /// Days or ranges of days like 1-5,7
+    day: Option<SolveDays>,
+    #[arg(long)]
+    /// Submit the results to the AoC server
+    submit: bool,
+    #[arg(long)]
+    /// Compare timings against the previous run
+    compare: bool,
+    #[arg(long, default_value_t = 10.0, requires = "compare")]
+    /// Highlight timing changes above this percentage
+    threshold: f64,
+    #[arg(long, short = 'j', default_value_t = 1)]
+    /// Number of days to run concurrently, without recording timings; 0 uses all available cores
+    jobs: usize,
+    #[arg(long)]
+    /// Time limit for each part, in seconds
+    timeout: Option<f64>,
+    #[arg(long, value_enum, default_value_t, conflicts_with_all = ["submit", "compare"])]
+    /// Output format of the results
+    format: Format,
+    #[arg(long, conflicts_with_all = ["submit", "compare"])]
+    /// Run the registered example inputs instead of your input files
+    example: bool,
+    #[arg(long, conflicts_with_all = ["submit", "example"])]
+    /// Run the days against every profile's input
+    all_profiles: bool,
+    #[arg(long, conflicts_with_all = ["submit", "compare", "example", "all_profiles"])]
+    /// Run on this file instead of your input file, or on stdin with "-"
+    input: Option<PathBuf>,
+    #[arg(long)]
+    /// Run only this part
+    part: Option<Part>,
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+    /// Override a parameter of the solution
+    params: Vec<(String, String)>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum SolveYear {
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveYear {
     All,
-    Year(u32),
+    Years(RangeInclusive<u32>),
+    /// Today's puzzle, which already selects a day
+    Today {
+        year: u32,
+        day: u32,
+    },
 }
 
 impl FromStr for SolveYear {
     type Err = ParseSolveYearError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let year = s.parse();
-        match year {
-            Ok(year) => Ok(Self::Year(year)),
-            Err(_) if s == "all" => Ok(Self::All),
-            Err(_) => Err(ParseSolveYearError),
+        match s {
+            "all" => return Ok(Self::All),
+            "latest" => {
+                let year = crate::get::latest_event();
+                return Ok(Self::Years(year..=year));
+            }
+            "today" => {
+                return match crate::get::today() {
+                    (year, 12, day) if day <= crate::get::days_in_event(year) => {
+                        Ok(Self::Today { year, day })
+                    }
+                    _ => Err(ParseSolveYearError::NoPuzzleToday),
+                };
+            }
+            _ => {}
         }
+
+        let parse = |year: &str| year.parse().map_err(|_| ParseSolveYearError::Invalid);
+        match s.split_once("..") {
+            Some((first, last)) => {
+                let (first, last) = (parse(first)?, parse(last)?);
+                if first > last {
+                    return Err(ParseSolveYearError::EmptyRange(first, last));
+                }
+                Ok(Self::Years(first..=last))
+            }
+            None => {
+                let year = parse(s)?;
+                Ok(Self::Years(year..=year))
+            }
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum ParseSolveYearError {
+    #[error("must be \"all\", \"latest\", \"today\", a year or a range of years like 2015..2025")]
+    Invalid,
+    #[error("the range {0}..{1} is empty")]
+    EmptyRange(u32, u32),
+    #[error("there is no puzzle today")]
+    NoPuzzleToday,
+}
+
+/// Days selected by a comma separated list of days and ranges of days
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveDays(SortedVecSet<u32>);
+
+impl FromStr for SolveDays {
+    type Err = ParseSolveDaysError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse = |day: &str| match day.trim().parse() {
+            Ok(day @ 1..=25) => Ok(day),
+            Ok(day) => Err(ParseSolveDaysError::OutOfRange(day)),
+            Err(_) => Err(ParseSolveDaysError::Invalid),
+        };
+
+        let mut days = SortedVecSet::with_capacity(25);
+        for selector in s.split(',') {
+            let (first, last) = match selector.split_once('-') {
+                Some((first, last)) => (parse(first)?, parse(last)?),
+                None => (parse(selector)?, parse(selector)?),
+            };
+            if first > last {
+                return Err(ParseSolveDaysError::EmptyRange(first, last));
+            }
+
+            for day in first..=last {
+                days.insert(day);
+            }
+        }
+
+        Ok(Self(days))
     }
 }
 
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum ParseSolveDaysError {
+    #[error("must be days or ranges of days like 1-5,7")]
+    Invalid,
+    #[error("the range {0}-{1} is empty")]
+    EmptyRange(u32, u32),
+    #[error("day {0} is not between 1 and 25")]
+    OutOfRange(u32),
+}
+
+/// Resolves the selectors to registered solutions
+///
+/// Selected days without a puzzle or a solution are skipped when several years are selected.
+pub fn select<'s>(
+    solutions: &'s Solutions,
+    year: &SolveYear,
+    days: Option<&SolveDays>,
+) -> anyhow::Result<Vec<RunEntry<'s>>> {
+    let years: Vec<u32> = match (year, days) {
+        (SolveYear::Today { .. }, Some(_)) => anyhow::bail!("\"today\" already selects a day"),
+        (SolveYear::Today { year, day }, None) => {
+            return Ok(vec![((*year, *day), solutions.get(*year, *day)?)]);
+        }
+        (SolveYear::All, None) => return Ok(solutions.all().collect()),
+        (SolveYear::All, Some(_)) => solutions.years().map(|(year, _)| year).collect(),
+        (SolveYear::Years(years), _) => years.clone().collect(),
+    };
+
+    let single = years.len() == 1;
+    let mut entries = Vec::new();
+    for year in years {
+        let Some(SolveDays(days)) = days else {
+            match solutions.year(year) {
+                Ok(days) => entries.extend(days),
+                Err(error) if single => return Err(error),
+                Err(_) => {}
+            }
+            continue;
+        };
+
+        for &day in days.iter() {
+            if day > crate::get::days_in_event(year) {
+                if single {
+                    anyhow::bail!("there is no puzzle {year}-{day}");
+                }
+                continue;
+            }
+
+            match solutions.get(year, day) {
+                Ok(solution) => entries.push(((year, day), solution)),
+                Err(error) if single => return Err(error),
+                Err(_) => {}
+            }
+        }
+    }
+
+    if entries.is_empty() {
+        anyhow::bail!("No solutions registered for the selected days");
+    }
+
+    Ok(entries)
+}
+
+fn parse_param(s: &str) -> Result<(String, String), ParseParamError> {
+    let (name, value) = s.split_once('=').ok_or(ParseParamError)?;
+    Ok((name.trim().to_string(), value.trim().to_string()))
+}
+
 #[derive(thiserror::Error, Debug)]
-#[error("must be \"all\" or a positive integer")]
-struct ParseSolveYearError;
+#[error("must be of the form NAME=VALUE")]
+pub struct ParseParamError;
 
 pub fn run_command(cli: SolveCli) -> anyhow::Result<()> {
     let solutions = Solutions::default();
+    let options = RunOptions {
+        submit: cli.submit,
+        compare: cli.compare.then_some(cli.threshold),
+        jobs: match cli.jobs {
+            0 => std::thread::available_parallelism().map_or(1, |n| n.get()),
+            jobs => jobs,
+        },
+        timeout: cli.timeout.map(Duration::try_from_secs_f64).transpose()?,
+        format: cli.format,
+        example: cli.example,
+        input: cli.input,
+        part: cli.part,
+        params: cli.params,
+    };
+
+    // These apply to specific days, so a whole year or event cannot be selected with them
+    let days_selected = cli.day.is_some() || matches!(cli.year, SolveYear::Today { .. });
+    let flags = [
+        ("--submit", options.submit),
+        ("--all-profiles", cli.all_profiles),
+        ("--input", options.input.is_some()),
+        ("--param", !options.params.is_empty()),
+    ];
+    if let Some((flag, _)) = flags.iter().find(|(_, set)| *set && !days_selected) {
+        anyhow::bail!("{flag} needs a day selection");
+    }
+
+    let entries = select(&solutions, &cli.year, cli.day.as_ref())?;
+    if options.input.is_some() && entries.len() > 1 {
+        anyhow::bail!("--input needs a single day");
+    }
+
+    if cli.all_profiles {
+        return run_all_profiles(&entries, &options);
+    }
+
+    Solutions::run(entries, &options)
+}
+
+fn run_all_profiles(entries: &[RunEntry<'_>], options: &RunOptions) -> anyhow::Result<()> {
+    let profiles = crate::profile::load()?;
+    if profiles.is_empty() {
+        anyhow::bail!("no profiles are configured");
+    }
+
+    use crate::util::style::{Color, ToStyled as _};
+
+    let mut error_count = 0;
+    for profile in profiles {
+        if options.format == Format::Text {
+            println!(
+                "{}",
+                format!("[{}]", profile.name).with_fg(Color::BrightWhite)
+            );
+        }
+
+        crate::profile::set_active(Some(profile));
+        match Solutions::run(entries.iter().copied(), options) {
+            Ok(()) => {}
+            Err(error) => match error.downcast::<SolutionErrors>() {
+                Ok(SolutionErrors(count)) => error_count += count,
+                Err(error) => {
+                    eprintln!("{error:#}");
+                    error_count += 1;
+                }
+            },
+        }
+    }
+
+    crate::profile::set_active(None);
+    if error_count == 0 {
+        Ok(())
+    } else {
+        Err(SolutionErrors(error_count).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{ParseSolveDaysError, ParseSolveYearError, SolveDays, SolveYear};
+    use crate::solution::{Solution, Solutions};
+
+    #[rstest]
+    #[case("all", Ok(SolveYear::All))]
+    #[case("2025", Ok(SolveYear::Years(2025..=2025)))]
+    #[case("2024..2025", Ok(SolveYear::Years(2024..=2025)))]
+    #[case("2025..2024", Err(ParseSolveYearError::EmptyRange(2025, 2024)))]
+    #[case("2024..", Err(ParseSolveYearError::Invalid))]
+    #[case("yesterday", Err(ParseSolveYearError::Invalid))]
+    fn test_parse_year(#[case] s: &str, #[case] expected: Result<SolveYear, ParseSolveYearError>) {
+        assert_eq!(s.parse(), expected);
+    }
+
+    #[rstest]
+    #[case("8", Ok(vec![8]))]
+    #[case("1-5", Ok(vec![1, 2, 3, 4, 5]))]
+    #[case("12,3,7", Ok(vec![3, 7, 12]))]
+    #[case("1-3,2-4", Ok(vec![1, 2, 3, 4]))]
+    #[case("5-1", Err(ParseSolveDaysError::EmptyRange(5, 1)))]
+    #[case("0", Err(ParseSolveDaysError::OutOfRange(0)))]
+    #[case("3,,4", Err(ParseSolveDaysError::Invalid))]
+    fn test_parse_days(#[case] s: &str, #[case] expected: Result<Vec<u32>, ParseSolveDaysError>) {
+        let result = s
+            .parse::<SolveDays>()
+            .map(|days| days.0.as_slice().to_vec());
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case(SolveYear::Years(2025..=2025), "12", Some(vec![(2025, 12)]))]
+    #[case(SolveYear::Years(2025..=2025), "12-13", None)]
+    #[case(SolveYear::Years(2024..=2025), "12-13", Some(vec![(2024, 13), (2025, 12)]))]
+    fn test_select_days_in_event(
+        #[case] year: SolveYear,
+        #[case] days: &str,
+        #[case] expected: Option<Vec<(u32, u32)>>,
+    ) {
+        let mut solutions = Solutions::empty();
+        for (year, day) in [(2024, 13), (2025, 12)] {
+            solutions.register(year, day, Solution::new());
+        }
 
-    match (cli.year, cli.day) {
-        (SolveYear::All, _) => solutions.run_all(),
-        (SolveYear::Year(year), Some(day)) => solutions.run_day(year, day),
-        (SolveYear::Year(year), None) => solutions.run_year(year),
+        let days = days.parse().unwrap();
+        let result = super::select(&solutions, &year, Some(&days))
+            .ok()
+            .map(|entries| entries.into_iter().map(|(key, _)| key).collect());
+        assert_eq!(result, expected);
     }
 }
@@ -0,0 +1,244 @@
+use std::time::Duration;
+
+use crate::{
+    answers::Refusal,
+    get::{ADVENT_OF_CODE_URI, GetError},
+    solution::{Part, Solutions},
+    util::output::Output,
+};
+
+#[derive(clap::Args)]
+/// Submit an answer to the AoC server
+pub struct SubmitCli {
+    #[arg()]
+    year: u32,
+    #[arg()]
+    day: u32,
+    #[arg()]
+    part: Part,
+    #[arg()]
+    /// Answer to submit; computed by running the solution when omitted
+    answer: Option<String>,
+}
+
+pub fn run_command(cli: SubmitCli) -> anyhow::Result<()> {
+    let answer = match cli.answer {
+        Some(answer) => answer,
+        None => compute_answer(cli.year, cli.day, cli.part)?,
+    };
+
+    println!("{}-{} {}: {answer}", cli.year, cli.day, cli.part);
+    let result = submit_answer(cli.year, cli.day, cli.part, &answer);
+    print_submit_result(&result);
+    result?;
+    Ok(())
+}
+
+fn compute_answer(year: u32, day: u32, part: Part) -> anyhow::Result<String> {
+    let solutions = Solutions::default();
+    let solution = solutions.get(year, day)?;
+    let f = solution
+        .part(part)
+        .ok_or_else(|| anyhow::anyhow!("No solution registered for part {}", part))?;
+
+    let input = crate::get::get_input(year, day)?;
+    let input = solution.prepare(&input)?;
+    let parsed = solution.parse(&input)?;
+    let params = solution.params(&[])?;
+    let (output, _) = (f)(&input, &parsed, &params)?;
+    if output.is_multiline() {
+        anyhow::bail!("Cannot submit a multiline answer");
+    }
+
+    Ok(format!("{output}"))
+}
+
+pub fn submit_output(
+    year: u32,
+    day: u32,
+    part: Part,
+    output: &dyn Output,
+) -> Result<Verdict, SubmitError> {
+    if output.is_multiline() {
+        return Err(SubmitError::Multiline);
+    }
+
+    let answer = format!("{output}");
+    submit_answer(year, day, part, &answer)
+}
+
+pub fn submit_answer(
+    year: u32,
+    day: u32,
+    part: Part,
+    answer: &str,
+) -> Result<Verdict, SubmitError> {
+    crate::answers::Answers::load(year, day)?.guard(part, answer)?;
+
+    let url = answer_url(year, day);
+    let level = part.level().to_string();
+
+    let body = crate::http::post_form(&url, &[("level", level.as_str()), ("answer", answer)])?;
+
+    let verdict = Verdict::parse(&body).ok_or(SubmitError::UnexpectedResponse)?;
+    tracing::debug!(year, day, %part, answer, ?verdict, "Answer submitted");
+    crate::answers::record(year, day, part, answer, verdict)?;
+
+    match verdict {
+        Verdict::Correct => Ok(verdict),
+        _ => Err(SubmitError::Rejected(verdict)),
+    }
+}
+
+pub fn print_submit_result(result: &Result<Verdict, SubmitError>) {
+    use crate::util::style::{Color, ToStyled as _};
+
+    match result {
+        Ok(verdict) => println!("  submitted: {}", verdict.with_fg(Color::Green)),
+        Err(SubmitError::Rejected(verdict)) => {
+            println!("  submitted: {}", verdict.with_fg(Color::Red))
+        }
+        Err(SubmitError::Refused(Refusal::AlreadyCorrect)) => {
+            let refusal = format!("{}", Refusal::AlreadyCorrect);
+            println!("  not submitted: {}", refusal.with_fg(Color::BrightBlack));
+        }
+        Err(SubmitError::Refused(refusal)) => {
+            let refusal = format!("{refusal}");
+            println!("  not submitted: {}", refusal.with_fg(Color::Yellow));
+        }
+        Err(error) => {
+            let error = format!("{error}");
+            println!("  submitted: {}", error.with_fg(Color::Red));
+        }
+    }
+}
+
+fn answer_url(year: u32, day: u32) -> String {
+    format!("{ADVENT_OF_CODE_URI}/{year}/day/{day}/answer")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect(Option<Hint>),
+    RateLimited(Duration),
+    AlreadySolved,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl Verdict {
+    fn parse(body: &str) -> Option<Self> {
+        let main = body
+            .split_once("<main>")
+            .and_then(|(_, rest)| rest.split_once("</main>"))
+            .map_or(body, |(main, _)| main);
+
+        if main.contains("That's the right answer") {
+            Some(Self::Correct)
+        } else if main.contains("That's not the right answer") {
+            let hint = if main.contains("your answer is too high") {
+                Some(Hint::TooHigh)
+            } else if main.contains("your answer is too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+            Some(Self::Incorrect(hint))
+        } else if main.contains("You gave an answer too recently") {
+            let wait = parse_wait_time(main).unwrap_or_default();
+            Some(Self::RateLimited(wait))
+        } else if main.contains("You don't seem to be solving the right level") {
+            Some(Self::AlreadySolved)
+        } else {
+            None
+        }
+    }
+}
+
+/// Parses the "You have 1m 23s left to wait" message of a rate limited submission
+fn parse_wait_time(body: &str) -> Option<Duration> {
+    let (_, rest) = body.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+
+    let mut seconds = 0;
+    for component in wait.split_whitespace() {
+        let (value, multiplier) = if let Some(value) = component.strip_suffix('h') {
+            (value, 3600)
+        } else if let Some(value) = component.strip_suffix('m') {
+            (value, 60)
+        } else if let Some(value) = component.strip_suffix('s') {
+            (value, 1)
+        } else {
+            return None;
+        };
+
+        seconds += value.parse::<u64>().ok()? * multiplier;
+    }
+
+    Some(Duration::from_secs(seconds))
+}
+
+impl std::fmt::Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => f.write_str("correct"),
+            Self::Incorrect(None) => f.write_str("incorrect"),
+            Self::Incorrect(Some(Hint::TooHigh)) => f.write_str("incorrect, too high"),
+            Self::Incorrect(Some(Hint::TooLow)) => f.write_str("incorrect, too low"),
+            Self::RateLimited(wait) => write!(f, "rate limited, wait {wait:?}"),
+            Self::AlreadySolved => f.write_str("already solved or not unlocked"),
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum SubmitError {
+    #[error("answer rejected: {0}")]
+    Rejected(Verdict),
+    #[error("refusing to submit: {0}")]
+    Refused(#[from] Refusal),
+    #[error("cannot submit a multiline answer")]
+    Multiline,
+    #[error("unexpected response from the AoC server")]
+    UnexpectedResponse,
+    #[error(transparent)]
+    Get(#[from] GetError),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use rstest::rstest;
+
+    use super::{Hint, Verdict};
+
+    #[rstest]
+    #[case("<p>That's the right answer!</p>", Verdict::Correct)]
+    #[case(
+        "<p>That's not the right answer; your answer is too high.</p>",
+        Verdict::Incorrect(Some(Hint::TooHigh))
+    )]
+    #[case(
+        "<p>That's not the right answer; your answer is too low.</p>",
+        Verdict::Incorrect(Some(Hint::TooLow))
+    )]
+    #[case("<p>That's not the right answer.</p>", Verdict::Incorrect(None))]
+    #[case(
+        "<p>You gave an answer too recently. You have 1m 5s left to wait.</p>",
+        Verdict::RateLimited(Duration::from_secs(65))
+    )]
+    #[case(
+        "<p>You don't seem to be solving the right level.</p>",
+        Verdict::AlreadySolved
+    )]
+    fn test_parse_verdict(#[case] body: &str, #[case] expected: Verdict) {
+        let result = Verdict::parse(body).unwrap();
+        assert_eq!(result, expected);
+    }
+}
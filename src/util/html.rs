@@ -0,0 +1,101 @@
+use std::borrow::Cow;
+
+/// Iterates over the contents of the non-nested elements delimited by `open` and `close`
+pub fn elements<'h>(
+    html: &'h str,
+    open: &'h str,
+    close: &'h str,
+) -> impl Iterator<Item = &'h str> + 'h {
+    let mut rest = html;
+    std::iter::from_fn(move || {
+        let (_, after_open) = rest.split_once(open)?;
+        let (contents, after_close) = after_open.split_once(close)?;
+        rest = after_close;
+        Some(contents)
+    })
+}
+
+/// Removes all tags, keeping their text contents
+pub fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some((before, after)) = rest.split_once('<') {
+        text.push_str(before);
+        rest = after.split_once('>').map_or("", |(_, after)| after);
+    }
+    text.push_str(rest);
+    text
+}
+
+/// Decodes the named and numeric character references used on the AoC website
+pub fn decode_entities(text: &str) -> Cow<'_, str> {
+    if !text.contains('&') {
+        return Cow::Borrowed(text);
+    }
+
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some((before, after)) = rest.split_once('&') {
+        decoded.push_str(before);
+
+        let entity = after
+            .split_once(';')
+            .filter(|(name, _)| name.len() <= 8)
+            .and_then(|(name, after)| decode_entity(name).map(|ch| (ch, after)));
+
+        match entity {
+            Some((ch, after)) => {
+                decoded.push(ch);
+                rest = after;
+            }
+            None => {
+                decoded.push('&');
+                rest = after;
+            }
+        }
+    }
+    decoded.push_str(rest);
+
+    Cow::Owned(decoded)
+}
+
+fn decode_entity(name: &str) -> Option<char> {
+    match name {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some('\u{a0}'),
+        _ => {
+            let code = if let Some(hex) = name.strip_prefix("#x") {
+                u32::from_str_radix(hex, 16).ok()?
+            } else {
+                name.strip_prefix('#')?.parse().ok()?
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("<p>Some <em>text</em></p>", "Some text")]
+    #[case("no tags", "no tags")]
+    #[case("unclosed <tag", "unclosed ")]
+    fn test_strip_tags(#[case] html: &str, #[case] expected: &str) {
+        assert_eq!(super::strip_tags(html), expected);
+    }
+
+    #[rstest]
+    #[case("a &lt;b&gt; &amp;&amp; c", "a <b> && c")]
+    #[case("&quot;&apos;&#65;&#x42;", "\"'AB")]
+    #[case("fish & chips", "fish & chips")]
+    #[case("&unknown; &#xZZ;", "&unknown; &#xZZ;")]
+    fn test_decode_entities(#[case] text: &str, #[case] expected: &str) {
+        assert_eq!(super::decode_entities(text), expected);
+    }
+}
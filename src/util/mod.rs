@@ -3,6 +3,7 @@
 pub mod bitmap;
 pub mod char;
 pub mod grid;
+pub mod html;
 pub mod output;
 pub mod slice;
 pub mod style;
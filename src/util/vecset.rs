@@ -1,3 +1,4 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SortedVecSet<T>(Vec<T>);
 
 impl<T> Default for SortedVecSet<T>
@@ -0,0 +1,193 @@
+use std::{
+    path::Path,
+    process::Command,
+    time::{Duration, SystemTime},
+};
+
+use crate::util::style::{Color, ToStyled as _};
+
+#[derive(clap::Args)]
+/// Re-run a day whenever its source or input file changes
+pub struct WatchCli {
+    #[arg()]
+    year: u32,
+    #[arg()]
+    day: u32,
+    #[arg(long, default_value_t = 0.5)]
+    /// Polling interval, in seconds
+    interval: f64,
+    #[arg(last = true)]
+    /// Extra arguments for the solve command, like --example or --param
+    args: Vec<String>,
+}
+
+pub fn run_command(cli: WatchCli) -> anyhow::Result<()> {
+    let interval = Duration::try_from_secs_f64(cli.interval)?;
+    let source = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join(format!("y{}", cli.year))
+        .join(format!("d{:02}.rs", cli.day));
+    // Examples run without an input directory, so there may be no input to watch
+    let input = crate::get::input_file_path(cli.year, cli.day).ok();
+
+    let mut source_modified = modified(&source);
+    let mut input_modified = input.as_deref().and_then(modified);
+    // This binary only stays up to date until the source changes for the first time
+    let mut rebuild = false;
+    let mut previous = Vec::new();
+    let mut reason = "started";
+
+    loop {
+        if rebuild {
+            println!("{}", "rebuilding...".with_fg(Color::BrightBlack));
+        }
+        let results = run(&cli, rebuild)?;
+        print!("\x1b[2J\x1b[H");
+        println!(
+            "{}",
+            format!("{}-{} ({reason})", cli.year, cli.day).with_fg(Color::BrightWhite)
+        );
+        print_results(&cli, &results, &previous);
+        if let Results::Records(records) = results {
+            previous = records;
+        }
+
+        reason = loop {
+            std::thread::sleep(interval);
+
+            let source_now = modified(&source);
+            let input_now = input.as_deref().and_then(modified);
+            if source_now != source_modified {
+                (source_modified, input_modified) = (source_now, input_now);
+                rebuild = true;
+                break "source changed";
+            }
+            if input_now != input_modified {
+                input_modified = input_now;
+                break "input changed";
+            }
+        };
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Results labelled by part, or the error output of a run without results
+enum Results {
+    Records(Vec<(String, Record)>),
+    Failed(String),
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+struct Record {
+    part: String,
+    example: Option<usize>,
+    output: Option<String>,
+    duration_ns: Option<u64>,
+    error: Option<String>,
+}
+
+/// Runs the solve command for the day, through cargo to pick up source changes
+fn run(cli: &WatchCli, rebuild: bool) -> anyhow::Result<Results> {
+    let mut command = match rebuild {
+        true => {
+            let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+            let mut command = Command::new(cargo);
+            command.current_dir(env!("CARGO_MANIFEST_DIR")).args([
+                "run",
+                "--release",
+                "--quiet",
+                "--",
+            ]);
+            command
+        }
+        false => Command::new(std::env::current_exe()?),
+    };
+
+    if let Some(profile) = crate::profile::active() {
+        command.args(["--profile", &profile.name]);
+    }
+
+    let output = command
+        .args(["solve", &cli.year.to_string(), &cli.day.to_string()])
+        .args(["--format", "json"])
+        .args(&cli.args)
+        .output()?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let records: Vec<_> = stdout
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Record>(line).ok())
+        .map(|record| {
+            let key = match record.example {
+                Some(example) => format!("{} (example {example})", record.part),
+                None => record.part.clone(),
+            };
+            (key, record)
+        })
+        .collect();
+
+    if records.is_empty() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Ok(Results::Failed(stderr.into_owned()));
+    }
+
+    Ok(Results::Records(records))
+}
+
+fn print_results(cli: &WatchCli, results: &Results, previous: &[(String, Record)]) {
+    let records = match results {
+        Results::Records(records) => records,
+        Results::Failed(stderr) => {
+            print!("{stderr}");
+            return;
+        }
+    };
+
+    for (key, record) in records {
+        print!("{}-{} {key}:", cli.year, cli.day);
+        let before = previous
+            .iter()
+            .find(|(previous, _)| previous == key)
+            .map(|(_, before)| before);
+
+        if let Some(error) = &record.error {
+            println!(" {}", error.with_fg(Color::Red));
+            continue;
+        }
+
+        let time = record.duration_ns.map(Duration::from_nanos);
+        let change = match (time, before.and_then(|b| b.duration_ns)) {
+            (Some(time), Some(before)) if before > 0 => {
+                let change =
+                    (time.as_secs_f64() / Duration::from_nanos(before).as_secs_f64() - 1.0) * 100.0;
+                format!(", {change:+.1}%")
+            }
+            _ => String::new(),
+        };
+        let time = time.map_or(String::new(), |time| format!("{time:?}"));
+
+        match &record.output {
+            Some(output) if output.contains('\n') => {
+                println!(" ({time}{change})");
+                for line in output.lines() {
+                    println!("  {line}");
+                }
+            }
+            Some(output) => println!(" {output} ({time}{change})"),
+            None => println!(" {time}{change}"),
+        }
+
+        if let Some(before) = before.and_then(|b| b.output.as_ref())
+            && record.output.as_ref() != Some(before)
+        {
+            let was = match before.contains('\n') {
+                true => "changed".to_string(),
+                false => format!("was {before}"),
+            };
+            println!("  {}", was.with_fg(Color::Yellow));
+        }
+    }
+}
@@ -4,6 +4,8 @@ use num::traits::Euclid;
 
 use crate::solution::Solution;
 
+crate::solution::register!(2025, 1);
+
 pub fn solution() -> Solution {
     Solution::new().with_a(a).with_b(b)
 }
@@ -1,14 +1,16 @@
-use crate::{solution::Solution, util::invalid_input};
+use crate::{input::Normalize, solution::Solution, util::invalid_input};
+
+crate::solution::register!(2025, 2);
 
 pub fn solution() -> Solution {
-    Solution::new().with_a(a).with_b(b)
+    Solution::new()
+        .with_input(Normalize::default() | Normalize::TRIM_END)
+        .with_a(a)
+        .with_b(b)
 }
 
 fn parse(fragment: &str) -> anyhow::Result<(u64, u64)> {
-    let (low, high) = fragment
-        .trim()
-        .split_once('-')
-        .ok_or_else(invalid_input!())?;
+    let (low, high) = fragment.split_once('-').ok_or_else(invalid_input!())?;
     let low = low.parse()?;
     let high = high.parse()?;
     Ok((low, high))
@@ -1,5 +1,7 @@
 use crate::{solution::Solution, util::invalid_input};
 
+crate::solution::register!(2025, 3);
+
 pub fn solution() -> Solution {
     Solution::new().with_a(a).with_b(b)
 }
@@ -2,10 +2,27 @@ use foldhash::HashSet;
 
 use crate::solution::Solution;
 
+crate::solution::register!(2025, 4);
+
 pub fn solution() -> Solution {
-    Solution::new().with_a(a).with_b(b)
+    Solution::new()
+        .with_a(a)
+        .with_b(b)
+        .with_example(EXAMPLE, Some("13"), Some("43"))
 }
 
+const EXAMPLE: &str = "..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.
+";
+
 fn parse(input: &str) -> HashSet<(i32, i32)> {
     let mut rolls = HashSet::default();
     for (y, line) in input.lines().enumerate() {
@@ -103,27 +120,17 @@ fn moore_neighborhood(x: i32, y: i32) -> impl Iterator<Item = (i32, i32)> {
 mod tests {
     use rstest::rstest;
 
-    const TEST_INPUT: &str = "..@@.@@@@.
-@@@.@.@.@@
-@@@@@.@.@@
-@.@@@@..@.
-@@.@@@@.@@
-.@@@@@@@.@
-.@.@.@.@@@
-@.@@@.@@@@
-.@@@@@@@@.
-@.@.@@@.@.
-";
+    use super::EXAMPLE;
 
     #[rstest]
-    #[case(TEST_INPUT)]
+    #[case(EXAMPLE)]
     fn test_a(#[case] input: &str) {
         let result = super::a(input).unwrap();
         assert_eq!(result, 13);
     }
 
     #[rstest]
-    #[case(TEST_INPUT)]
+    #[case(EXAMPLE)]
     fn test_b(#[case] input: &str) {
         let result = super::b(input).unwrap();
         assert_eq!(result, 43);
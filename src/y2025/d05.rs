@@ -2,10 +2,29 @@ use std::{ops::RangeInclusive, str::FromStr};
 
 use crate::{solution::Solution, util::invalid_input};
 
+crate::solution::register!(2025, 5);
+
 pub fn solution() -> Solution {
-    Solution::new().with_a(a).with_b(b)
+    Solution::parsed(parse)
+        .with_a(a)
+        .with_b(b)
+        .with_example(EXAMPLE, Some("3"), Some("14"))
+        .into()
 }
 
+const EXAMPLE: &str = "3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32
+";
+
 fn parse(input: &str) -> anyhow::Result<(Ranges, Vec<u64>)> {
     let mut lines = input.lines();
     let ranges = (&mut lines)
@@ -22,29 +41,12 @@ fn parse(input: &str) -> anyhow::Result<(Ranges, Vec<u64>)> {
     Ok((ranges, ids))
 }
 
-fn parse_ranges(input: &str) -> anyhow::Result<Ranges> {
-    let ranges = input
-        .lines()
-        .take_while(|l| !l.is_empty())
-        .map(|line| {
-            let (a, b) = line.split_once('-').ok_or_else(invalid_input!())?;
-            let a = a.parse()?;
-            let b = b.parse()?;
-            Ok::<_, anyhow::Error>(a..=b)
-        })
-        .collect::<Result<_, _>>()?;
-    let ranges = RangesBuilder(ranges).build();
-    Ok(ranges)
-}
-
-fn a(input: &str) -> anyhow::Result<usize> {
-    let (ranges, ids) = parse(input)?;
+fn a((ranges, ids): &(Ranges, Vec<u64>)) -> anyhow::Result<usize> {
     let count = ids.iter().filter(|id| ranges.contains(id)).count();
     Ok(count)
 }
 
-fn b(input: &str) -> anyhow::Result<u64> {
-    let ranges = parse_ranges(input)?;
+fn b((ranges, _): &(Ranges, Vec<u64>)) -> anyhow::Result<u64> {
     let count = ranges.iter().map(|r| r.len()).sum();
     Ok(count)
 }
@@ -126,30 +128,19 @@ where
 mod tests {
     use rstest::rstest;
 
-    const TEST_INPUT: &str = "3-5
-10-14
-16-20
-12-18
-
-1
-5
-8
-11
-17
-32
-";
+    use super::EXAMPLE;
 
     #[rstest]
-    #[case(TEST_INPUT, 3)]
+    #[case(EXAMPLE, 3)]
     fn test_a(#[case] input: &str, #[case] expected: usize) {
-        let result = super::a(input).unwrap();
+        let result = super::a(&super::parse(input).unwrap()).unwrap();
         assert_eq!(result, expected);
     }
 
     #[rstest]
-    #[case(TEST_INPUT, 14)]
+    #[case(EXAMPLE, 14)]
     fn test_b(#[case] input: &str, #[case] expected: u64) {
-        let result = super::b(input).unwrap();
+        let result = super::b(&super::parse(input).unwrap()).unwrap();
         assert_eq!(result, expected);
     }
 }
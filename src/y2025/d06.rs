@@ -2,10 +2,21 @@ use std::str::FromStr;
 
 use crate::{solution::Solution, util::invalid_input};
 
+crate::solution::register!(2025, 6);
+
 pub fn solution() -> Solution {
-    Solution::new().with_a(a).with_b(b)
+    Solution::new()
+        .with_a(a)
+        .with_b(b)
+        .with_example(EXAMPLE, Some("4277556"), Some("3263827"))
 }
 
+const EXAMPLE: &str = "123 328  51 64 
+ 45 64  387 23 
+  6 98  215 314
+*   +   *   +  
+";
+
 fn a(input: &str) -> anyhow::Result<u64> {
     let mut lines = input.lines();
     let operators: Vec<_> = lines
@@ -104,21 +115,17 @@ impl FromStr for Op {
 mod tests {
     use rstest::rstest;
 
-    pub const TEST_INPUT: &str = "123 328  51 64 
- 45 64  387 23 
-  6 98  215 314
-*   +   *   +  
-";
+    use super::EXAMPLE;
 
     #[rstest]
-    #[case(TEST_INPUT, 4277556)]
+    #[case(EXAMPLE, 4277556)]
     fn test_a(#[case] input: &str, #[case] expected: u64) {
         let result = super::a(input).unwrap();
         assert_eq!(result, expected);
     }
 
     #[rstest]
-    #[case(TEST_INPUT, 3263827)]
+    #[case(EXAMPLE, 3263827)]
     fn test_b(#[case] input: &str, #[case] expected: u64) {
         let result = super::b(input).unwrap();
         assert_eq!(result, expected);
@@ -3,10 +3,33 @@ use crate::{
     util::{char::FromChar, grid::Grid},
 };
 
+crate::solution::register!(2025, 7);
+
 pub fn solution() -> Solution {
-    Solution::new().with_a(a).with_b(b)
+    Solution::new()
+        .with_a(a)
+        .with_b(b)
+        .with_example(EXAMPLE, Some("21"), Some("40"))
 }
 
+const EXAMPLE: &str = ".......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............
+";
+
 fn parse(input: &str) -> anyhow::Result<Grid<Tile>> {
     let mut width = 0;
     let mut height = 0;
@@ -108,33 +131,17 @@ impl FromChar for Tile {
 mod tests {
     use rstest::rstest;
 
-    const TEST_INPUT: &str = ".......S.......
-...............
-.......^.......
-...............
-......^.^......
-...............
-.....^.^.^.....
-...............
-....^.^...^....
-...............
-...^.^...^.^...
-...............
-..^...^.....^..
-...............
-.^.^.^.^.^...^.
-...............
-";
+    use super::EXAMPLE;
 
     #[rstest]
-    #[case(TEST_INPUT, 21)]
+    #[case(EXAMPLE, 21)]
     fn test_a(#[case] input: &str, #[case] expected: u64) {
         let result = super::a(input).unwrap();
         assert_eq!(result, expected);
     }
 
     #[rstest]
-    #[case(TEST_INPUT, 40)]
+    #[case(EXAMPLE, 40)]
     fn test_b(#[case] input: &str, #[case] expected: u64) {
         let result = super::b(input).unwrap();
         assert_eq!(result, expected);
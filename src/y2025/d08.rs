@@ -11,10 +11,39 @@ use crate::{
     },
 };
 
+crate::solution::register!(2025, 8);
+
 pub fn solution() -> Solution {
-    Solution::new().with_a(|i| a(i, 1000)).with_b(b)
+    Solution::parsed(parse)
+        .with_param("connections", "1000")
+        .with_a_params(|nodes, p| a(nodes, p.get("connections")?))
+        .with_b(|nodes| b(nodes))
+        .with_example_params(EXAMPLE, &[("connections", "10")], Some("40"), Some("25272"))
+        .into()
 }
 
+const EXAMPLE: &str = "162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689
+";
+
 fn parse_node(line: &str) -> nom::IResult<&str, IVec3> {
     use nom::{
         Parser,
@@ -34,9 +63,8 @@ fn parse(input: &str) -> anyhow::Result<Vec<IVec3>> {
     Ok(parsed)
 }
 
-fn a(input: &str, connections: u64) -> anyhow::Result<u64> {
-    let nodes = parse(input)?;
-    let mut candidates = Edge::candidates(&nodes);
+fn a(nodes: &[IVec3], connections: u64) -> anyhow::Result<u64> {
+    let mut candidates = Edge::candidates(nodes);
     let mut circuits = Circuits::new(nodes.len());
 
     for _ in 0..connections {
@@ -58,9 +86,8 @@ fn a(input: &str, connections: u64) -> anyhow::Result<u64> {
     Ok(a * b * c)
 }
 
-fn b(input: &str) -> anyhow::Result<i64> {
-    let nodes = parse(input)?;
-    let mut candidates = Edge::candidates(&nodes);
+fn b(nodes: &[IVec3]) -> anyhow::Result<i64> {
+    let mut candidates = Edge::candidates(nodes);
     let mut circuits = Circuits::new(nodes.len());
 
     let last_edge;
@@ -190,38 +217,20 @@ impl Circuits {
 mod tests {
     use rstest::rstest;
 
-    const TEST_INPUT: &str = "162,817,812
-57,618,57
-906,360,560
-592,479,940
-352,342,300
-466,668,158
-542,29,236
-431,825,988
-739,650,466
-52,470,668
-216,146,977
-819,987,18
-117,168,530
-805,96,715
-346,949,466
-970,615,88
-941,993,340
-862,61,35
-984,92,344
-425,690,689
-";
+    use super::EXAMPLE;
 
     #[rstest]
-    #[case(TEST_INPUT, 10, 40)]
+    #[case(EXAMPLE, 10, 40)]
     fn test_a(#[case] input: &str, #[case] connections: u64, #[case] expected: u64) {
-        let result = super::a(input, connections).unwrap();
+        let nodes = super::parse(input).unwrap();
+        let result = super::a(&nodes, connections).unwrap();
         assert_eq!(result, expected);
     }
     #[rstest]
-    #[case(TEST_INPUT, 25272)]
+    #[case(EXAMPLE, 25272)]
     fn test_b(#[case] input: &str, #[case] expected: i64) {
-        let result = super::b(input).unwrap();
+        let nodes = super::parse(input).unwrap();
+        let result = super::b(&nodes).unwrap();
         assert_eq!(result, expected);
     }
 }
@@ -10,10 +10,26 @@ use crate::{
     },
 };
 
+crate::solution::register!(2025, 9);
+
 pub fn solution() -> Solution {
-    Solution::new().with_a(a).with_b(b)
+    Solution::parsed(parse)
+        .with_a(|red_tiles| a(red_tiles))
+        .with_b(|red_tiles| b(red_tiles))
+        .with_example(EXAMPLE, Some("50"), Some("24"))
+        .into()
 }
 
+const EXAMPLE: &str = "7,1
+11,1
+11,7
+9,7
+9,5
+2,5
+2,3
+7,3
+";
+
 fn parse(input: &str) -> anyhow::Result<Vec<IVec2>> {
     let parser = |l: &str| {
         let (x, y) = l.split_once(',').ok_or_else(invalid_input!())?;
@@ -25,8 +41,7 @@ fn parse(input: &str) -> anyhow::Result<Vec<IVec2>> {
     input.lines().map(parser).collect::<Result<_, _>>()
 }
 
-fn a(input: &str) -> anyhow::Result<i64> {
-    let red_tiles = parse(input)?;
+fn a(red_tiles: &[IVec2]) -> anyhow::Result<i64> {
     let max = red_tiles
         .iter()
         .tuple_combinations()
@@ -36,9 +51,8 @@ fn a(input: &str) -> anyhow::Result<i64> {
     Ok(max)
 }
 
-fn b(input: &str) -> anyhow::Result<i64> {
-    let red_tiles = parse(input)?;
-    let segments = Segments::from_loop(&red_tiles)?;
+fn b(red_tiles: &[IVec2]) -> anyhow::Result<i64> {
+    let segments = Segments::from_loop(red_tiles)?;
 
     let mut max = None;
     for (a, b) in red_tiles.iter().tuple_combinations() {
@@ -149,18 +163,9 @@ impl Segments {
 mod tests {
     use rstest::rstest;
 
+    use super::EXAMPLE;
     use crate::util::vector::{IVec2, vec2};
 
-    const TEST_INPUT: &str = "7,1
-11,1
-11,7
-9,7
-9,5
-2,5
-2,3
-7,3
-";
-
     #[rstest]
     #[case(vec2(2, 5), vec2(9, 7), 24)]
     #[case(vec2(7, 1), vec2(11, 7), 35)]
@@ -172,16 +177,18 @@ mod tests {
     }
 
     #[rstest]
-    #[case(TEST_INPUT, 50)]
+    #[case(EXAMPLE, 50)]
     fn test_a(#[case] input: &str, #[case] expected: i64) {
-        let result = super::a(input).unwrap();
+        let red_tiles = super::parse(input).unwrap();
+        let result = super::a(&red_tiles).unwrap();
         assert_eq!(result, expected);
     }
 
     #[rstest]
-    #[case(TEST_INPUT, 24)]
+    #[case(EXAMPLE, 24)]
     fn test_b(#[case] input: &str, #[case] expected: i64) {
-        let result = super::b(input).unwrap();
+        let red_tiles = super::parse(input).unwrap();
+        let result = super::b(&red_tiles).unwrap();
         assert_eq!(result, expected);
     }
 }
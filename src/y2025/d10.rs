@@ -2,6 +2,8 @@ use z3::{Optimize, SatResult, ast::Int};
 
 use crate::solution::Solution;
 
+crate::solution::register!(2025, 10);
+
 pub fn solution() -> Solution {
     Solution::new().with_a(a).with_b(b)
 }
@@ -2,11 +2,45 @@ use foldhash::HashMap;
 
 use crate::{solution::Solution, util::invalid_input};
 
+crate::solution::register!(2025, 11);
+
 pub fn solution() -> Solution {
-    Solution::new().with_a(a).with_b(b)
+    Solution::parsed(parse)
+        .with_a(a)
+        .with_b(b)
+        .with_example(EXAMPLE_A, Some("5"), None)
+        .with_example(EXAMPLE_B, None, Some("2"))
+        .into()
 }
 
-fn parse<'i>(input: &'i str) -> anyhow::Result<Graph<'i>> {
+const EXAMPLE_A: &str = "aaa: you hhh
+you: bbb ccc
+bbb: ddd eee
+ccc: ddd eee fff
+ddd: ggg
+eee: out
+fff: out
+ggg: out
+hhh: ccc fff iii
+iii: out
+";
+
+const EXAMPLE_B: &str = "svr: aaa bbb
+aaa: fft
+fft: ccc
+bbb: tty
+tty: ccc
+ccc: ddd eee
+ddd: hub
+hub: fff
+eee: dac
+dac: fff
+fff: ggg hhh
+ggg: out
+hhh: out
+";
+
+fn parse(input: &str) -> anyhow::Result<Graph> {
     let mut graph = Graph::default();
     for line in input.lines() {
         let (from, to) = line.split_once(": ").ok_or_else(invalid_input!())?;
@@ -28,23 +62,21 @@ fn get_node_idx(network: &Graph, name: &str) -> anyhow::Result<NodeIdx> {
     Ok(idx)
 }
 
-fn a(input: &str) -> anyhow::Result<u64> {
-    let network = parse(input)?;
-    let you = get_node_idx(&network, "you")?;
-    let out = get_node_idx(&network, "out")?;
-    let count = count_paths(&network, you, out);
+fn a(network: &Graph) -> anyhow::Result<u64> {
+    let you = get_node_idx(network, "you")?;
+    let out = get_node_idx(network, "out")?;
+    let count = count_paths(network, you, out);
     Ok(count)
 }
 
-fn b(input: &str) -> anyhow::Result<u64> {
-    let network = parse(input)?;
-    let svr = get_node_idx(&network, "svr")?;
-    let dac = get_node_idx(&network, "dac")?;
-    let fft = get_node_idx(&network, "fft")?;
-    let out = get_node_idx(&network, "out")?;
+fn b(network: &Graph) -> anyhow::Result<u64> {
+    let svr = get_node_idx(network, "svr")?;
+    let dac = get_node_idx(network, "dac")?;
+    let fft = get_node_idx(network, "fft")?;
+    let out = get_node_idx(network, "out")?;
 
-    let dac_fft = count_paths(&network, dac, fft);
-    let fft_dac = count_paths(&network, fft, dac);
+    let dac_fft = count_paths(network, dac, fft);
+    let fft_dac = count_paths(network, fft, dac);
 
     let (int1, int2, int_paths) = match (dac_fft, fft_dac) {
         (0, 0) => return Ok(0),
@@ -53,8 +85,8 @@ fn b(input: &str) -> anyhow::Result<u64> {
         (_, _) => return Err(anyhow::anyhow!("unexpected cyclic graph")),
     };
 
-    let svr_int1 = count_paths(&network, svr, int1);
-    let int2_out = count_paths(&network, int2, out);
+    let svr_int1 = count_paths(network, svr, int1);
+    let int2_out = count_paths(network, int2, out);
 
     let count = svr_int1 * int_paths * int2_out;
     Ok(count)
@@ -85,9 +117,9 @@ fn count_paths(network: &Graph, from: NodeIdx, to: NodeIdx) -> u64 {
 }
 
 #[derive(Debug, Default, Clone)]
-struct Graph<'n> {
-    names: HashMap<&'n str, NodeIdx>,
-    nodes: Vec<Node<'n>>,
+struct Graph {
+    names: HashMap<String, NodeIdx>,
+    nodes: Vec<Node>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -96,21 +128,21 @@ struct NodeIdx(u32);
 
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
-struct Node<'n> {
-    name: &'n str,
+struct Node {
+    name: String,
     idx: NodeIdx,
     neighbors: Vec<NodeIdx>,
 }
 
-impl<'n> Graph<'n> {
-    pub fn insert_node(&mut self, name: &'n str) -> NodeIdx {
+impl Graph {
+    pub fn insert_node(&mut self, name: &str) -> NodeIdx {
         if let Some(&idx) = self.names.get(name) {
             idx
         } else {
             let idx = self.next_node_idx();
             let node = Node::new(name, idx);
             self.nodes.push(node);
-            self.names.insert(name, idx);
+            self.names.insert(name.to_string(), idx);
             idx
         }
     }
@@ -123,19 +155,19 @@ impl<'n> Graph<'n> {
         NodeIdx(self.nodes.len() as u32)
     }
 
-    fn node(&self, idx: NodeIdx) -> &Node<'n> {
+    fn node(&self, idx: NodeIdx) -> &Node {
         &self.nodes[idx.0 as usize]
     }
 
-    fn node_mut(&mut self, idx: NodeIdx) -> &mut Node<'n> {
+    fn node_mut(&mut self, idx: NodeIdx) -> &mut Node {
         &mut self.nodes[idx.0 as usize]
     }
 }
 
-impl<'n> Node<'n> {
-    pub fn new(name: &'n str, idx: NodeIdx) -> Self {
+impl Node {
+    pub fn new(name: &str, idx: NodeIdx) -> Self {
         Self {
-            name,
+            name: name.to_string(),
             idx,
             neighbors: Vec::new(),
         }
@@ -143,14 +175,14 @@ impl<'n> Node<'n> {
 }
 
 #[allow(dead_code)]
-struct Dot<'g>(&'g Graph<'g>);
+struct Dot<'g>(&'g Graph);
 
 impl std::fmt::Display for Dot<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "strict digraph {{")?;
 
         for node in self.0.nodes.iter() {
-            let attrs = match node.name {
+            let attrs = match node.name.as_str() {
                 "you" | "out" | "svr" | "dac" | "fft" => {
                     ",style=filled,fillcolor=black,fontcolor=white"
                 }
@@ -174,44 +206,21 @@ impl std::fmt::Display for Dot<'_> {
 mod tests {
     use rstest::rstest;
 
-    const TEST_INPUT_A: &str = "aaa: you hhh
-you: bbb ccc
-bbb: ddd eee
-ccc: ddd eee fff
-ddd: ggg
-eee: out
-fff: out
-ggg: out
-hhh: ccc fff iii
-iii: out
-";
+    use super::{EXAMPLE_A, EXAMPLE_B};
 
     #[rstest]
-    #[case(TEST_INPUT_A, 5)]
+    #[case(EXAMPLE_A, 5)]
     fn test_a(#[case] input: &str, #[case] expected: u64) {
-        let result = super::a(input).unwrap();
+        let network = super::parse(input).unwrap();
+        let result = super::a(&network).unwrap();
         assert_eq!(result, expected);
     }
 
-    const TEST_INPUT_B: &str = "svr: aaa bbb
-aaa: fft
-fft: ccc
-bbb: tty
-tty: ccc
-ccc: ddd eee
-ddd: hub
-hub: fff
-eee: dac
-dac: fff
-fff: ggg hhh
-ggg: out
-hhh: out
-";
-
     #[rstest]
-    #[case(TEST_INPUT_B, 2)]
+    #[case(EXAMPLE_B, 2)]
     fn test_b(#[case] input: &str, #[case] expected: u64) {
-        let result = super::b(input).unwrap();
+        let network = super::parse(input).unwrap();
+        let result = super::b(&network).unwrap();
         assert_eq!(result, expected);
     }
 }
@@ -3,12 +3,13 @@ use nalgebra::SimdPartialOrd;
 
 use crate::{solution::Solution, util::vector::IVec2};
 
+crate::solution::register!(2025, 12);
+
 pub fn solution() -> Solution {
-    Solution::new().with_a(a)
+    Solution::parsed(parser::parse).with_a(a).into()
 }
 
-fn a(input: &str) -> anyhow::Result<u64> {
-    let (shapes, regions) = parser::parse(input)?;
+fn a((shapes, regions): &(Vec<Shape>, Vec<Region>)) -> anyhow::Result<u64> {
     let upper_shape_bound = shapes
         .iter()
         .map(|s| s.size)
@@ -23,7 +24,7 @@ fn a(input: &str) -> anyhow::Result<u64> {
         }
 
         let (can_fit_upper_piece_count, can_fit_exact_piece_count) =
-            can_fit_piece_count(&shapes, region);
+            can_fit_piece_count(shapes, region);
 
         if can_fit_upper_piece_count != can_fit_exact_piece_count {
             return Err(anyhow::anyhow!(